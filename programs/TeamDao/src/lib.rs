@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use team::*;
+use tournament::*;
 
 pub mod team;
+pub mod tournament;
 
 declare_id!("FuQvo5fjJ2A3P3DXgSWsYX8Hsawd2Qg7LwohfSKhEBpu");
 
@@ -10,173 +12,355 @@ pub mod team_dao {
 
     use super::*;
 
-    use team::adding_member::{adding_member, AddMember};
+    use team::accepting_invite::{accepting_invite, AcceptInvite};
+    use team::approving_join_request::{
+        approving_join_request, approving_join_request_by_vote, ApproveJoinRequest,
+        ApproveJoinRequestByVote,
+    };
     use team::can_join::{can_join, CanJoinTournament};
-    use team::claiming_reward::{claiming_reward, ClaimReward};
-    use team::creating_team::{creating_team, CreateTeam};
-    use team::handle_distribute_proposal::{
-        handle_distribute_proposal, DistributionProposalHandler,
+    use team::casting_vote::{casting_vote, CastVote};
+    use team::claiming_reward::{
+        claiming_reward, claiming_token_reward, ClaimReward, ClaimTokenReward,
     };
-    use team::initing_percentage_proposal::{initing_percentage_proposal, InitPercentageProposal};
-    use team::initing_tournament::{initing_tournament, InitTournament};
+    use team::closing_token_vault::{closing_token_vault, CloseTokenVault};
+    use team::creating_proposal::{creating_proposal, CreateProposal};
+    use team::creating_team::{creating_team, CreateTeam};
+    use team::declining_invite::{declining_invite, DeclineInvite};
+    use team::disbanding_team::{disbanding_team, DisbandTeam};
+    use team::executing_proposal::{executing_proposal, ExecuteProposal};
+    use team::finalizing_proposal::{finalizing_proposal, FinalizeProposal};
+    use team::initing_registry::{initing_registry, InitRegistry};
+    use team::inviting_member::{inviting_member, InviteMember};
     use team::leaving_team::{leaving_team, LeaveTeam};
-    use team::leaving_tournament::{leaving_tournament, LeaveTournament};
+    use team::migrating_team::{migrating_team, MigrateTeam};
+    use team::rejecting_join_request::{rejecting_join_request, RejectJoinRequest};
     use team::removing_member::{removing_member, RemoveMember};
+    use team::requesting_to_join::{requesting_to_join, RequestToJoin};
+    use team::revoking_invite::{revoking_invite, RevokeInvite};
+    use team::setting_member_role::{setting_member_role, SetMemberRole};
+    use team::setting_open_to_join::{setting_open_to_join, SetOpenToJoin};
+    use team::setting_role_permissions::{setting_role_permissions, SetRolePermissions};
     use team::transfering_captain::{transfering_captain, TransferCaptain};
-    use team::voting_for_tournament::{voting_for_tournament, VoteForTournament};
-    use team::VoteType;
+    use team::updating_team_metadata::{updating_team_metadata, UpdateTeamMetadata};
+    use team::{GovernanceConfig, ProposalKind, Role, TeamMetadata, VoteType};
+    use tournament::closing_registration::{closing_registration, CloseRegistration};
+    use tournament::creating_tournament::{creating_tournament, CreateTournament};
+    use tournament::finalizing_tournament::{finalizing_tournament, FinalizeTournament};
+    use tournament::opening_registration::{opening_registration, OpenRegistration};
+    use tournament::reporting_result::{
+        reporting_result, reporting_token_result, ReportResult, ReportTokenResult,
+    };
+
+    // ----------------------------------------------
+
+    // instructions that can be called by anyone before the first team is created
+
+    // creating the team registry, called once after the program is deployed
+    pub fn init_registry(ctx: Context<InitRegistry>) -> Result<()> {
+        return initing_registry(ctx);
+    }
 
     // ----------------------------------------------
 
     // instructions that can be called by captain
+    // the members whose role has the permission of an instruction can call it as well
+    // the instructions marked as council actions can also be called by a co-captain and
+    // need the signatures of the council threshold, the co-signers are passed as remaining accounts
 
     // creating team
     // @param team_name: name of the team, used to create pda
-    // @param team_id: id of the team, used to create pda
-    pub fn create_team(ctx: Context<CreateTeam>, team_name: String, team_id: u64) -> Result<()> {
-        return creating_team(ctx, team_name, team_id);
+    // @param team_id: id of the team, used to create pda, must be the team count of the registry
+    // @param name_hash: sha256 hash of the trimmed and lowercased name, used to reserve the name
+    // @param governance: quorum and approval percentages of the team votes
+    // @param min_roster_size: minimum number of players the team plays with
+    // @param max_roster_size: maximum number of members of the team
+    // @param metadata: optional tag, logo, banner, game, region and social links of the team
+    pub fn create_team(
+        ctx: Context<CreateTeam>,
+        team_name: String,
+        team_id: u64,
+        name_hash: [u8; 32],
+        governance: GovernanceConfig,
+        min_roster_size: u8,
+        max_roster_size: u8,
+        metadata: TeamMetadata,
+    ) -> Result<()> {
+        return creating_team(
+            ctx,
+            team_name,
+            team_id,
+            name_hash,
+            governance,
+            min_roster_size,
+            max_roster_size,
+            metadata,
+        );
     }
 
-    // adding member to team
-    // @param team_name: name of the team, used in pda
-    // @param team_id: id of the team, used in pda
-    // @param member: member's public key to add to team
-    pub fn add_member(
-        ctx: Context<AddMember>,
-        _team_name: String,
-        _team_id: u64,
-        member: Pubkey,
+    // updating the metadata of the team
+    // @param metadata: new tag, logo, banner, game, region and social links of the team
+    pub fn update_team_metadata(
+        ctx: Context<UpdateTeamMetadata>,
+        metadata: TeamMetadata,
     ) -> Result<()> {
-        return adding_member(ctx, _team_name, _team_id, member);
+        return updating_team_metadata(ctx, metadata);
     }
 
-    // removing member from team
-    // @param team_name: name of the team, used in pda
-    // @param team_id: id of the team, used in pda
-    // @param member: member's public key to remove from team
-    pub fn remove_member(
-        ctx: Context<RemoveMember>,
-        _team_name: String,
-        _team_id: u64,
-        member: Pubkey,
+    // disbanding team, closes the team account and returns its rent to the captain, council action
+    pub fn disband_team(ctx: Context<DisbandTeam>) -> Result<()> {
+        return disbanding_team(ctx);
+    }
+
+    // sweeping the token vault of the team to the captain and closing it, council action
+    // a team whose last prize was paid in spl tokens closes its token vault before it is disbanded
+    pub fn close_token_vault(ctx: Context<CloseTokenVault>) -> Result<()> {
+        return closing_token_vault(ctx);
+    }
+
+    // inviting member to team, the member is added when the invitation is accepted, council action
+    // @param member: member's public key to invite to team
+    pub fn invite_member(ctx: Context<InviteMember>, member: Pubkey) -> Result<()> {
+        return inviting_member(ctx, member);
+    }
+
+    // revoking a pending invitation
+    pub fn revoke_invite(ctx: Context<RevokeInvite>) -> Result<()> {
+        return revoking_invite(ctx);
+    }
+
+    // approving a join request, adds the applicant to the team, council action
+    pub fn approve_join_request(ctx: Context<ApproveJoinRequest>) -> Result<()> {
+        return approving_join_request(ctx);
+    }
+
+    // rejecting a join request
+    pub fn reject_join_request(ctx: Context<RejectJoinRequest>) -> Result<()> {
+        return rejecting_join_request(ctx);
+    }
+
+    // opening or closing the team, join requests of an open team are accepted automatically, council action
+    // @param open_to_join: whether the team accepts join requests automatically
+    pub fn set_open_to_join(ctx: Context<SetOpenToJoin>, open_to_join: bool) -> Result<()> {
+        return setting_open_to_join(ctx, open_to_join);
+    }
+
+    // assigning a role to a member, the captain and the co-captains cannot be assigned
+    // @param member: member's public key
+    // @param role: new role of the member
+    pub fn set_member_role(ctx: Context<SetMemberRole>, member: Pubkey, role: Role) -> Result<()> {
+        return setting_member_role(ctx, member, role);
+    }
+
+    // setting the starting players of the team for the active tournament, the others are on the bench
+    // @param lineup: public keys of the starting players
+    pub fn set_lineup(ctx: Context<SetLineup>, lineup: Vec<Pubkey>) -> Result<()> {
+        return setting_lineup(ctx, lineup);
+    }
+
+    // changing the permissions of a role, the captain keeps every permission
+    // @param role: role to change
+    // @param permissions: permission bits of the role
+    pub fn set_role_permissions(
+        ctx: Context<SetRolePermissions>,
+        role: Role,
+        permissions: u16,
     ) -> Result<()> {
-        return removing_member(ctx, _team_name, _team_id, member);
+        return setting_role_permissions(ctx, role, permissions);
     }
 
-    // transferring captain role to another member
-    // @param team_name: name of the team, used in pda
-    // @param team_id: id of the team, used in pda
+    // removing member from team, council action
+    // @param member: member's public key to remove from team
+    pub fn remove_member(ctx: Context<RemoveMember>, member: Pubkey) -> Result<()> {
+        return removing_member(ctx, member);
+    }
+
+    // transferring captain role to another member, council action
     // @param member: member's public key to transfer captain role to
-    pub fn transfer_captain(
-        ctx: Context<TransferCaptain>,
-        _team_name: String,
-        _team_id: u64,
-        member: Pubkey,
-    ) -> Result<()> {
-        return transfering_captain(ctx, _team_name, _team_id, member);
+    pub fn transfer_captain(ctx: Context<TransferCaptain>, member: Pubkey) -> Result<()> {
+        return transfering_captain(ctx, member);
+    }
+
+    // ----------------------------------------------
+    // instructions that can be called by the invited players
+
+    // accepting the invitation, adds the signer to the team
+    pub fn accept_invite(ctx: Context<AcceptInvite>) -> Result<()> {
+        return accepting_invite(ctx);
+    }
+
+    // declining the invitation
+    pub fn decline_invite(ctx: Context<DeclineInvite>) -> Result<()> {
+        return declining_invite(ctx);
+    }
+
+    // ----------------------------------------------
+    // instructions that can be called by the players that want to join a team
+
+    // requesting to join the team
+    pub fn request_to_join(ctx: Context<RequestToJoin>) -> Result<()> {
+        return requesting_to_join(ctx);
     }
 
     // ----------------------------------------------
     // instructions that can be called by anyone by players in the team
 
     // leaving team
-    // @param team_name: name of the team, used in pda
-    // @param team_id: id of the team, used in pda
-    pub fn leave_team(ctx: Context<LeaveTeam>, _team_name: String, _team_id: u64) -> Result<()> {
-        return leaving_team(ctx, _team_name, _team_id);
-    }
-
-    // init tournament
-    // @param tournament_name: name of the tournament, used in pda
-    // @param tournament_id: id of the tournament, used in pda
-    // @param tournament_prize: prize of the tournament
-    pub fn init_tournament(
-        ctx: Context<InitTournament>,
-        _team_name: String,
-        _team_id: u64,
-        tournament_address: Pubkey,
-        tournament_prize: u64,
-    ) -> Result<()> {
-        return initing_tournament(
-            ctx,
-            _team_name,
-            _team_id,
-            tournament_address,
-            tournament_prize,
-        );
+    pub fn leave_team(ctx: Context<LeaveTeam>) -> Result<()> {
+        return leaving_team(ctx);
     }
 
-    // vote for tournament
-    // @param _team_name : name of the team, used in pda
-    // @param _team_id : id of the team, used in pda
-    // @param tournament_address : tournament address"
-    pub fn vote_for_tournament(
-        ctx: Context<VoteForTournament>,
-        _team_name: String,
-        _team_id: u64,
-        vote_type: VoteType,
-    ) -> Result<()> {
-        return voting_for_tournament(ctx, _team_name, _team_id, vote_type);
+    // joining, leaving and the reward distribution of a tournament are decided with proposals
+    // the function below will use the logic to decide if a team can join the tournament or not
+
+    // can join the tournament, we will use this function to decide if a team can join the tournament or not
+    // if the team can join, it is registered to the tournament account and the entry fee is paid
+    pub fn can_join_tournament(ctx: Context<CanJoinTournament>) -> Result<()> {
+        return can_join(ctx);
     }
 
-    // leave a tournament
+    // migrate a team account created before the versioning to the current layout, can be called by anyone
     // @param _team_name : name of the team, used in pda
     // @param _team_id : id of the team, used in pda
-    pub fn leave_tournament(
-        ctx: Context<LeaveTournament>,
+    // @param name_hash: sha256 hash of the trimmed and lowercased name, used to reserve the name
+    pub fn migrate_team(
+        ctx: Context<MigrateTeam>,
         _team_name: String,
         _team_id: u64,
-        vote_type: VoteType,
+        name_hash: [u8; 32],
     ) -> Result<()> {
-        return leaving_tournament(ctx, _team_name, _team_id, vote_type);
+        return migrating_team(ctx, _team_name, _team_id, name_hash);
     }
 
-    // init percentage proposal
-    // @param _team_name : name of the team, used in pda
-    // @param _team_id : id of the team, used in pda
-    pub fn init_percentage_proposal(
-        ctx: Context<InitPercentageProposal>,
-        _team_name: String,
-        _team_id: u64,
-        percentages: Vec<u8>,
+    // distribute rewards
+    pub fn claim_reward(ctx: Context<ClaimReward>, reward: u64) -> Result<()> {
+        return claiming_reward(ctx, reward);
+    }
+
+    // distribute rewards paid in spl tokens
+    pub fn claim_token_reward(ctx: Context<ClaimTokenReward>, reward: u64) -> Result<()> {
+        return claiming_token_reward(ctx, reward);
+    }
+
+    // paying the sol rewards of every member at once and settling the prize, can be called by anyone
+    // the member accounts are passed as remaining accounts in the order of the distribution shares
+    pub fn distribute_rewards<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeRewards<'info>>,
     ) -> Result<()> {
-        return initing_percentage_proposal(ctx, _team_name, _team_id, percentages);
+        return distributing_rewards(ctx);
     }
 
-    // reward distribution proposal handler
-    // @param _team_name : name of the team, used in pda
-    // @param _team_id : id of the team, used in pda
-    pub fn distribution_proposal_handler(
-        ctx: Context<DistributionProposalHandler>,
-        _team_name: String,
-        _team_id: u64,
-        vote_type: VoteType,
+    // ----------------------------------------------
+    // generic team proposals
+
+    // create a proposal, can be called by any member of the team
+    // @param kind : governance action of the proposal
+    // @param payload : borsh serialized argument of the governance action
+    // @param voting_period : seconds the proposal is open for votes, 30 days at most
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        kind: ProposalKind,
+        payload: Vec<u8>,
+        voting_period: i64,
     ) -> Result<()> {
-        return handle_distribute_proposal(ctx, _team_name, _team_id, vote_type);
+        return creating_proposal(ctx, kind, payload, voting_period);
     }
-    // two functions above will basically be used to vote for the distribution of the rewards
-    // the function below will use the logic to decide if a team can join the tournament or not
 
-    // can join the tournament, we will use this function to decide if a team can join the tournament or not
-    // @param _team_name : name of the team, used in pda
-    // @param _team_id : id of the team, used in pda
-    pub fn can_join_tournament(
-        ctx: Context<CanJoinTournament>,
-        _team_name: String,
-        _team_id: u64,
+    // cast a vote for a proposal, can be called by any member of the team once
+    // @param vote_type : yes, no or abstain
+    pub fn cast_vote(ctx: Context<CastVote>, vote_type: VoteType) -> Result<()> {
+        return casting_vote(ctx, vote_type);
+    }
+
+    // execute a passed proposal, can be called by anyone until 7 days after its deadline
+    // joining a tournament and the reward distribution are council actions
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        return executing_proposal(ctx);
+    }
+
+    // approve a join request with a passed proposal, can be called by anyone until 7 days after its deadline
+    pub fn approve_join_request_by_vote(ctx: Context<ApproveJoinRequestByVote>) -> Result<()> {
+        return approving_join_request_by_vote(ctx);
+    }
+
+    // finalize an expired proposal, can be called by anyone
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        return finalizing_proposal(ctx);
+    }
+
+    // ----------------------------------------------
+    // instructions that can be called by the tournament organizer
+
+    // creating tournament
+    // @param tournament_name: name of the tournament, used to create pda
+    // @param entry_fee: lamports paid by every team that registers
+    // @param prize_pool: lamports deposited by the organizer for the prizes
+    // @param start_slot: slot the tournament starts
+    // @param end_slot: slot the tournament ends, it can be finalized after this slot
+    // @param max_teams: maximum number of teams that can register
+    // @param oracle: address that can report results besides the organizer
+    // @param roster_size: number of players every registered team plays with
+    pub fn create_tournament(
+        ctx: Context<CreateTournament>,
+        tournament_name: String,
+        entry_fee: u64,
+        prize_pool: u64,
+        start_slot: u64,
+        end_slot: u64,
+        max_teams: u8,
+        oracle: Pubkey,
+        roster_size: u8,
     ) -> Result<()> {
-        return can_join(ctx, _team_name, _team_id);
+        return creating_tournament(
+            ctx,
+            tournament_name,
+            entry_fee,
+            prize_pool,
+            start_slot,
+            end_slot,
+            max_teams,
+            oracle,
+            roster_size,
+        );
     }
 
-    // distribute rewards
-    // @param _team_name : name of the team, used in pda
-    // @param _team_id : id of the team, used in pda
-    pub fn claim_reward(
-        ctx: Context<ClaimReward>,
-        _team_name: String,
-        _team_id: u64,
-        reward: u64,
+    // opening the registration of the tournament
+    pub fn open_registration(ctx: Context<OpenRegistration>) -> Result<()> {
+        return opening_registration(ctx);
+    }
+
+    // closing the registration of the tournament
+    pub fn close_registration(ctx: Context<CloseRegistration>) -> Result<()> {
+        return closing_registration(ctx);
+    }
+
+    // finalizing the tournament after it ends
+    pub fn finalize_tournament(ctx: Context<FinalizeTournament>) -> Result<()> {
+        return finalizing_tournament(ctx);
+    }
+
+    // closing the tournament after the result of every registered team is reported
+    // the remainder of the prize pool and the rent go back to the organizer
+    pub fn close_tournament(ctx: Context<CloseTournament>) -> Result<()> {
+        return closing_tournament(ctx);
+    }
+
+    // reporting the result of a team, can be called by the organizer or the oracle
+    // the prize is released from the prize pool into the team vault
+    // @param placement : placement of the team in the tournament
+    // @param prize : lamports won by the team
+    pub fn report_result(ctx: Context<ReportResult>, placement: u8, prize: u64) -> Result<()> {
+        return reporting_result(ctx, placement, prize);
+    }
+
+    // reporting the result of a team with a prize paid in spl tokens
+    // the organizer deposits the prize to the team token vault before reporting it
+    // @param placement : placement of the team in the tournament
+    // @param prize : prize of the team in the smallest unit of the prize mint
+    pub fn report_token_result(
+        ctx: Context<ReportTokenResult>,
+        placement: u8,
+        prize: u64,
     ) -> Result<()> {
-        return claiming_reward(ctx, _team_name, _team_id, reward);
+        return reporting_token_result(ctx, placement, prize);
     }
 }
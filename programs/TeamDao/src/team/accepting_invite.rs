@@ -0,0 +1,46 @@
+use super::*;
+
+pub fn accepting_invite(ctx: Context<AcceptInvite>) -> Result<()> {
+    let team = &mut ctx.accounts.team_account;
+    let invitee = *ctx.accounts.invitee.key;
+
+    // adding member to the team, the invitation is closed
+    team.add_member(invitee)?;
+
+    emit!(InvitationClosed {
+        team: team.key(),
+        invitee,
+        accepted: true,
+    });
+    emit!(MemberAdded {
+        team: team.key(),
+        member: invitee,
+    });
+
+    msg!(
+        "{} is successfully added to the team {}",
+        invitee,
+        team.name
+    );
+
+    Ok(())
+}
+
+// accept invite instruction, signed by the invitee
+// the rent of the invitation goes back to the inviter
+#[derive(Accounts)]
+pub struct AcceptInvite<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut, seeds=[b"invite", team_account.key().as_ref(), invitee.key().as_ref()], bump = invitation_account.bump, has_one = inviter, close = inviter)]
+    pub invitation_account: Account<'info, InvitationAccount>,
+
+    #[account(mut)]
+    pub inviter: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub invitee: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
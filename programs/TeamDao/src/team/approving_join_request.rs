@@ -0,0 +1,114 @@
+use super::errors::ErrorCode;
+use super::*;
+
+pub fn approving_join_request(ctx: Context<ApproveJoinRequest>) -> Result<()> {
+    let team = &mut ctx.accounts.team_account;
+    let applicant = ctx.accounts.applicant.key();
+
+    // checking if the signer can manage join requests
+    team.require_permission(ctx.accounts.signer.key, Permission::MANAGE_JOIN_REQUESTS)?;
+    // checking if the council approved the new member, as for an invitation
+    team.check_council_approval(ctx.accounts.signer.key, ctx.remaining_accounts)?;
+
+    // adding member to the team, the join request is closed
+    team.add_member(applicant)?;
+
+    emit!(JoinRequestClosed {
+        team: team.key(),
+        applicant,
+        approved: true,
+    });
+    emit!(MemberAdded {
+        team: team.key(),
+        member: applicant,
+    });
+
+    msg!(
+        "{} is successfully added to the team {}",
+        applicant,
+        team.name
+    );
+
+    Ok(())
+}
+
+pub fn approving_join_request_by_vote(ctx: Context<ApproveJoinRequestByVote>) -> Result<()> {
+    let team = &mut ctx.accounts.team_account;
+    let proposal = &mut ctx.accounts.proposal_account;
+    let applicant = ctx.accounts.applicant.key();
+
+    // checking if the proposal is passed, not executed yet and not expired
+    proposal.require_executable(Clock::get()?.unix_timestamp)?;
+    // checking if the proposal is for approving a join request
+    require!(
+        proposal.kind == ProposalKind::ApproveJoinRequest,
+        ErrorCode::InvalidProposalKindError
+    );
+    // checking if the proposal is for this applicant
+    require!(
+        decode_payload::<Pubkey>(&proposal.payload)? == applicant,
+        ErrorCode::InvalidProposalPayloadError
+    );
+
+    // adding member to the team, the join request is closed
+    team.add_member(applicant)?;
+    proposal.status = ProposalStatus::Executed;
+
+    emit!(JoinRequestClosed {
+        team: team.key(),
+        applicant,
+        approved: true,
+    });
+    emit!(MemberAdded {
+        team: team.key(),
+        member: applicant,
+    });
+
+    msg!(
+        "{} is successfully added to the team {}",
+        applicant,
+        team.name
+    );
+
+    Ok(())
+}
+
+// approve join request instruction, a council action
+// the rent of the join request goes back to the applicant
+#[derive(Accounts)]
+pub struct ApproveJoinRequest<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut, seeds=[b"join_request", team_account.key().as_ref(), applicant.key().as_ref()], bump = join_request_account.bump, has_one = applicant, close = applicant)]
+    pub join_request_account: Account<'info, JoinRequestAccount>,
+
+    #[account(mut)]
+    pub applicant: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// approve join request with a passed proposal, can be called by anyone
+#[derive(Accounts)]
+pub struct ApproveJoinRequestByVote<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut, seeds=[b"proposal".as_ref(), team_account.key().as_ref(), &proposal_account.id.to_le_bytes()], bump = proposal_account.bump)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(mut, seeds=[b"join_request", team_account.key().as_ref(), applicant.key().as_ref()], bump = join_request_account.bump, has_one = applicant, close = applicant)]
+    pub join_request_account: Account<'info, JoinRequestAccount>,
+
+    #[account(mut)]
+    pub applicant: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,11 +1,24 @@
 use super::errors::ErrorCode;
 use super::*;
+use crate::tournament::{TournamentAccount, TournamentStatus};
+use anchor_lang::system_program;
 
-pub fn can_join(ctx: Context<CanJoinTournament>, _team_name: String, _team_id: u64) -> Result<()> {
+pub fn can_join(ctx: Context<CanJoinTournament>) -> Result<()> {
     let team = &mut ctx.accounts.team_account;
+    let roster_size = ctx.accounts.tournament_account.roster_size;
 
-    // checking if the team has 5 players to join the tournament
-    require!(team.members.len() == 5, ErrorCode::NotEnoughPlayersError);
+    // checking if the tournament is played in a format of the team
+    require!(
+        roster_size >= team.min_roster_size && roster_size <= team.max_roster_size,
+        ErrorCode::RosterSizeMismatchError
+    );
+    // checking if the lineup is set for the tournament with as many players as it requires
+    // the members on the bench do not count
+    require!(
+        team.lineup_tournament == ctx.accounts.tournament_account.key()
+            && team.lineup.len() == roster_size as usize,
+        ErrorCode::NotEnoughPlayersError
+    );
 
     // checking if the team has an active tournament
     require!(
@@ -13,22 +26,93 @@ pub fn can_join(ctx: Context<CanJoinTournament>, _team_name: String, _team_id: u
         ErrorCode::NoActiveTournamentError
     );
 
-    // checking if voting result is yes, all players voted for tournament and distribution
-    if team.voting_result == true && team.distribution_yes_votes > 2 {
+    // checking if the tournament account is the active tournament of the team
+    require!(
+        team.active_tournament == ctx.accounts.tournament_account.key(),
+        ErrorCode::InvalidTournamentError
+    );
+
+    // the tournament is joined with a passed proposal, checking if the distribution is accepted too
+    if team.distribution_voting_result == true {
         team.can_join_tournament = true;
     } else {
         team.can_join_tournament = false;
     }
 
+    if team.can_join_tournament {
+        // checking if the accepted shares still belong to the members of the team
+        // a member that left after the distribution vote needs a new distribution proposal
+        team.validate_distribution(&team.distribution_shares)?;
+
+        let team_key = team.key();
+        let tournament = &mut ctx.accounts.tournament_account;
+
+        // checking if the tournament accepts registrations
+        require!(
+            tournament.status == TournamentStatus::RegistrationOpen,
+            ErrorCode::InvalidTournamentStatusError
+        );
+        // checking if the tournament has not started yet
+        require!(
+            Clock::get()?.slot < tournament.start_slot,
+            ErrorCode::TournamentStartedError
+        );
+        // checking if the team is not registered yet
+        require!(
+            !tournament.registered_teams.contains(&team_key),
+            ErrorCode::TeamAlreadyRegisteredError
+        );
+        // checking if the tournament has room for the team
+        require!(
+            tournament.registered_teams.len() < tournament.max_teams as usize,
+            ErrorCode::TournamentFullError
+        );
+
+        // registering the team to the tournament
+        tournament.registered_teams.push(team_key);
+        // freezing the roster until the tournament is settled
+        team.roster_locked = true;
+        // the prize and the leave votes of the tournament follow the roster and the distribution at the entry
+        team.take_entry_snapshot();
+
+        // paying the entry fee, it is added to the prize pool of the tournament
+        let entry_fee = tournament.entry_fee;
+        tournament.prize_pool += entry_fee;
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: ctx.accounts.tournament_account.to_account_info(),
+                },
+            ),
+            entry_fee,
+        )?;
+
+        emit!(TournamentJoined {
+            team: team_key,
+            tournament: ctx.accounts.tournament_account.key(),
+            entry_fee,
+        });
+
+        msg!(
+            "{} is successfully registered to the tournament {}",
+            ctx.accounts.team_account.name,
+            ctx.accounts.tournament_account.name
+        );
+    }
+
     Ok(())
 }
 // can join tournament
 #[derive(Accounts)]
-#[instruction(_team_name: String, _team_id: u64)]
 pub struct CanJoinTournament<'info> {
-    #[account(mut, seeds=[_team_name.as_bytes(), &_team_id.to_ne_bytes()], bump = team_account.bump)]
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
     pub team_account: Account<'info, TeamAccount>,
 
+    #[account(mut, seeds=[b"tournament", tournament_account.organizer.as_ref(), tournament_account.name.as_bytes()], bump = tournament_account.bump)]
+    pub tournament_account: Account<'info, TournamentAccount>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
 
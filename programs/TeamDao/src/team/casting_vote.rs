@@ -0,0 +1,77 @@
+use super::errors::ErrorCode;
+use super::*;
+
+pub fn casting_vote(ctx: Context<CastVote>, vote_type: VoteType) -> Result<()> {
+    let team = &ctx.accounts.team_account;
+    let proposal = &mut ctx.accounts.proposal_account;
+
+    // checking if the proposal is still open for votes
+    require!(
+        proposal.status == ProposalStatus::Active,
+        ErrorCode::ProposalNotActiveError
+    );
+    // checking if the voting period is not over
+    require!(
+        Clock::get()?.unix_timestamp <= proposal.deadline,
+        ErrorCode::ProposalExpiredError
+    );
+    // checking if the signer is in the team
+    require!(
+        team.members.contains(ctx.accounts.signer.key),
+        ErrorCode::MemberNotInTeamError
+    );
+    // checking if the signer can vote for proposals of this kind
+    require!(
+        team.is_proposal_voter(ctx.accounts.signer.key, proposal.kind),
+        ErrorCode::PermissionDeniedError
+    );
+    // checking if the member did not vote yet
+    require!(
+        !proposal.voters.contains(ctx.accounts.signer.key),
+        ErrorCode::AlreadyVotedError
+    );
+
+    // checking vote type
+    match vote_type {
+        VoteType::Yes => proposal.yes_votes += 1,
+        VoteType::No => proposal.no_votes += 1,
+        VoteType::Abstain => proposal.abstain_votes += 1,
+    }
+    // adding the member to voters
+    proposal.voters.push(*ctx.accounts.signer.key);
+
+    // deciding the proposal if the result cannot change anymore
+    let team_size = team.count_proposal_voters(proposal.kind);
+    proposal.update_status(&team.governance, team_size);
+
+    emit!(ProposalVoteCast {
+        team: team.key(),
+        proposal: proposal.key(),
+        voter: *ctx.accounts.signer.key,
+        vote_type,
+        status: proposal.status,
+    });
+
+    msg!(
+        "{} successfully voted for the proposal {}",
+        ctx.accounts.signer.key,
+        proposal.id
+    );
+
+    Ok(())
+}
+
+// cast vote instruction
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut, seeds=[b"proposal".as_ref(), team_account.key().as_ref(), &proposal_account.id.to_le_bytes()], bump = proposal_account.bump)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
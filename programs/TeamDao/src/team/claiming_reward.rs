@@ -1,54 +1,180 @@
 use super::errors::ErrorCode;
 use super::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
-pub fn claiming_reward(
-    ctx: Context<ClaimReward>,
-    _team_name: String,
-    _team_id: u64,
-    reward: u64,
-) -> Result<()> {
+pub fn claiming_reward(ctx: Context<ClaimReward>, reward: u64) -> Result<()> {
     let team = &mut ctx.accounts.team_account;
 
-    // checking if the account_info key exists in team members
+    // checking if the prize of the tournament is paid in sol
     require!(
-        team.members.contains(ctx.accounts.to.key),
-        ErrorCode::MemberNotInTeamError
+        team.prize_mint == Pubkey::default(),
+        ErrorCode::InvalidPrizeMintError
     );
+    // checking if the prize is released into the vault by the tournament
+    require!(team.result_reported, ErrorCode::ResultNotReportedError);
 
-    // get the index of to account
-    let index = team
-        .members
-        .iter()
-        .position(|&r| r == *ctx.accounts.to.key)
-        .unwrap();
+    // recording the claim, the member cannot claim more than its share
+    record_claim(team, ctx.accounts.to.key, reward)?;
+    // the tournament is over for the team once the last reward is claimed
+    if team.is_prize_settled() {
+        team.clear_active_tournament();
+    }
+
+    // checking if the vault holds enough lamports for the reward
+    require!(
+        vault_available_lamports(&ctx.accounts.vault.to_account_info())? >= reward,
+        ErrorCode::VaultNotFundedError
+    );
+
+    // paying the reward from the team vault, the vault pda signs the transfer
+    let team_key = team.key();
+    let vault_seeds: &[&[u8]] = &[b"vault".as_ref(), team_key.as_ref(), &[team.vault_bump]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.to.to_account_info(),
+            },
+            &[vault_seeds],
+        ),
+        reward,
+    )?;
+
+    emit!(RewardClaimed {
+        team: team_key,
+        member: *ctx.accounts.to.key,
+        amount: reward,
+        mint: Pubkey::default(),
+    });
+
+    Ok(())
+}
 
-    let expected_max_reward = team.prize * team.distribution_percentages[index] as u64 / 100;
+pub fn claiming_token_reward(ctx: Context<ClaimTokenReward>, reward: u64) -> Result<()> {
+    let team = &ctx.accounts.team_account;
 
-    // checking if the index matches the percentage of the prize and reward distribution
+    // checking if the prize of the tournament is paid in spl tokens
     require!(
-        reward <= expected_max_reward,
-        ErrorCode::InvalidPercentageError
+        team.prize_mint != Pubkey::default(),
+        ErrorCode::InvalidPrizeMintError
     );
+    // checking if the prize is reported by the tournament
+    require!(team.result_reported, ErrorCode::ResultNotReportedError);
 
-    let from = ctx.accounts.from.to_account_info();
-    let to = ctx.accounts.to.to_account_info();
+    // recording the claim, the member cannot claim more than its share
+    record_claim(&mut ctx.accounts.team_account, ctx.accounts.to.key, reward)?;
+    // the tournament is over for the team once the last reward is claimed
+    if ctx.accounts.team_account.is_prize_settled() {
+        ctx.accounts.team_account.clear_active_tournament();
+    }
 
-    // Debit from_account and credit to_account
-    **from.try_borrow_mut_lamports()? -= reward;
-    **to.try_borrow_mut_lamports()? += reward;
+    let team = &ctx.accounts.team_account;
+
+    // paying the reward from the team token vault, the team pda signs the transfer
+    let team_id = team.id.to_le_bytes();
+    let team_seeds: &[&[u8]] = &[team.name.as_bytes(), team_id.as_ref(), &[team.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.prize_vault.to_account_info(),
+                to: ctx.accounts.to_token_account.to_account_info(),
+                authority: team.to_account_info(),
+            },
+            &[team_seeds],
+        ),
+        reward,
+    )?;
+
+    emit!(RewardClaimed {
+        team: team.key(),
+        member: *ctx.accounts.to.key,
+        amount: reward,
+        mint: team.prize_mint,
+    });
+
+    Ok(())
+}
+
+// returns the maximum reward the member can get from the prize
+fn max_reward_of(team: &TeamAccount, member: &Pubkey) -> Result<u64> {
+    // checking if the member earned the prize, a member that left after the entry keeps its share
+    require!(
+        team.prize_members().contains(member),
+        ErrorCode::MemberNotInTeamError
+    );
+
+    Ok(team.reward_of(member))
+}
+
+// returns the part of the prize that is not claimed by the members yet
+// a settled prize has nothing left to claim, the remainder of the vault belongs to the team
+pub fn unclaimed_reward(team: &TeamAccount) -> u64 {
+    if team.prize_settled {
+        return 0;
+    }
+
+    team.prize_members()
+        .iter()
+        .map(|member| {
+            team.reward_of(member)
+                .saturating_sub(claimed_reward_of(team, member))
+        })
+        .sum()
+}
+
+// returns the part of the prize the member already got
+pub fn claimed_reward_of(team: &TeamAccount, member: &Pubkey) -> u64 {
+    team.claimed_rewards
+        .iter()
+        .find(|r| r.member == *member)
+        .map_or(0, |r| r.amount)
+}
+
+// adds the reward to the claimed amount of the member
+// the total claimed amount is capped at the share of the member
+pub fn record_claim(team: &mut TeamAccount, member: &Pubkey, reward: u64) -> Result<()> {
+    // checking if the rewards are not distributed yet
+    require!(!team.prize_settled, ErrorCode::PrizeSettledError);
+
+    let max_reward = max_reward_of(team, member)?;
+
+    match team
+        .claimed_rewards
+        .iter_mut()
+        .find(|r| r.member == *member)
+    {
+        Some(record) => {
+            // an overflowing total exceeds the share of the member as well
+            let total = record
+                .amount
+                .checked_add(reward)
+                .ok_or(ErrorCode::RewardAlreadyClaimedError)?;
+            require!(total <= max_reward, ErrorCode::RewardAlreadyClaimedError);
+            record.amount = total;
+        }
+        None => {
+            require!(reward <= max_reward, ErrorCode::RewardAlreadyClaimedError);
+            team.claimed_rewards.push(ClaimRecord {
+                member: *member,
+                amount: reward,
+            });
+        }
+    }
 
     Ok(())
 }
 
 // distribute rewards
 #[derive(Accounts)]
-#[instruction(_team_name: String, _team_id: u64)]
 pub struct ClaimReward<'info> {
-    #[account(mut, seeds=[_team_name.as_bytes(), &_team_id.to_ne_bytes()], bump = team_account.bump)]
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
     pub team_account: Account<'info, TeamAccount>,
-    #[account(mut)]
-    /// CHECK: This is not dangerous
-    pub from: AccountInfo<'info>,
+    // prize vault of the team, the reward is paid only from here
+    #[account(mut, seeds=[b"vault", team_account.key().as_ref()], bump = team_account.vault_bump)]
+    pub vault: SystemAccount<'info>,
     #[account(mut)]
     /// CHECK: This is not dangerous because we just pay to this account
     pub to: AccountInfo<'info>,
@@ -56,3 +182,23 @@ pub struct ClaimReward<'info> {
     pub user: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
+
+// distribute rewards paid in spl tokens
+#[derive(Accounts)]
+pub struct ClaimTokenReward<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+    #[account(constraint = prize_mint.key() == team_account.prize_mint @ ErrorCode::InvalidPrizeMintError)]
+    pub prize_mint: Account<'info, Mint>,
+    // token vault of the team, the reward is paid only from here
+    #[account(mut, associated_token::mint = prize_mint, associated_token::authority = team_account)]
+    pub prize_vault: Account<'info, TokenAccount>,
+    /// CHECK: This is not dangerous because it is only used to derive the member token account
+    pub to: AccountInfo<'info>,
+    // associated token account of the member that receives the reward
+    #[account(mut, associated_token::mint = prize_mint, associated_token::authority = to)]
+    pub to_token_account: Account<'info, TokenAccount>,
+    #[account()]
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
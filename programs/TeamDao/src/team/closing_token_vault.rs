@@ -0,0 +1,93 @@
+use super::errors::ErrorCode;
+use super::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+pub fn closing_token_vault(ctx: Context<CloseTokenVault>) -> Result<()> {
+    let team = &ctx.accounts.team_account;
+
+    // checking if the signer is the captain
+    require!(
+        team.captain == *ctx.accounts.signer.key,
+        ErrorCode::NotCaptainError
+    );
+    // checking if the council approved closing the vault
+    team.check_council_approval(ctx.accounts.signer.key, ctx.remaining_accounts)?;
+    // checking if the team is not in a tournament
+    require!(
+        team.active_tournament == Pubkey::default(),
+        ErrorCode::ActiveTournamentError
+    );
+    // checking if the members claimed their share of the prize
+    require!(unclaimed_reward(team) == 0, ErrorCode::UnclaimedPrizeError);
+
+    // sweeping the remainder of the token vault to the captain, the rounding dust included
+    let team_id = team.id.to_le_bytes();
+    let team_seeds: &[&[u8]] = &[team.name.as_bytes(), team_id.as_ref(), &[team.bump]];
+    let amount = ctx.accounts.prize_vault.amount;
+    if amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.prize_vault.to_account_info(),
+                    to: ctx.accounts.to_token_account.to_account_info(),
+                    authority: team.to_account_info(),
+                },
+                &[team_seeds],
+            ),
+            amount,
+        )?;
+    }
+
+    // closing the token vault, its rent goes back to the captain
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.prize_vault.to_account_info(),
+            destination: ctx.accounts.signer.to_account_info(),
+            authority: team.to_account_info(),
+        },
+        &[team_seeds],
+    ))?;
+
+    let team_key = team.key();
+    let prize_mint = ctx.accounts.prize_mint.key();
+
+    // the token vault of the last prize is closed, the team can be disbanded
+    let team = &mut ctx.accounts.team_account;
+    if team.prize_mint == prize_mint {
+        team.prize_mint = Pubkey::default();
+    }
+
+    emit!(TokenVaultClosed {
+        team: team_key,
+        mint: prize_mint,
+        amount,
+    });
+
+    msg!("The token vault of {} is successfully closed", team.name);
+
+    Ok(())
+}
+
+// close token vault instruction, sweeps the token vault of the team to the captain and closes it
+#[derive(Accounts)]
+pub struct CloseTokenVault<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    pub prize_mint: Account<'info, Mint>,
+
+    // token vault of the team, the associated token account of the team pda
+    #[account(mut, associated_token::mint = prize_mint, associated_token::authority = team_account)]
+    pub prize_vault: Account<'info, TokenAccount>,
+
+    // associated token account of the captain that receives the remainder
+    #[account(mut, associated_token::mint = prize_mint, associated_token::authority = signer)]
+    pub to_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,91 @@
+use super::errors::ErrorCode;
+use super::*;
+
+// co-captains of the team and the number of approvals the sensitive actions need
+// the captain is always part of the council
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct CouncilConfig {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+impl CouncilConfig {
+    pub const MAX_MEMBERS: usize = 4;
+
+    // checks the size of the council and the threshold, the membership is checked against the team
+    pub fn validate(&self) -> Result<()> {
+        let mut members = self.members.clone();
+        members.sort();
+        members.dedup();
+
+        require!(
+            members.len() == self.members.len()
+                && self.members.len() <= Self::MAX_MEMBERS
+                && self.threshold > 0
+                && self.threshold as usize <= self.members.len() + 1,
+            ErrorCode::InvalidCouncilError
+        );
+
+        Ok(())
+    }
+}
+
+impl TeamAccount {
+    pub fn is_council_member(&self, key: &Pubkey) -> bool {
+        self.captain == *key || self.council.contains(key)
+    }
+
+    // checks if the signer and the co-signing council members reach the council threshold
+    // the permission of the signer is checked by the instruction, it only approves if it is in the council
+    // the co-signers are passed as remaining accounts of the instruction
+    pub fn check_council_approval(
+        &self,
+        signer: &Pubkey,
+        co_signers: &[AccountInfo],
+    ) -> Result<()> {
+        let mut approvals = vec![];
+        if self.is_council_member(signer) {
+            approvals.push(*signer);
+        }
+        for co_signer in co_signers {
+            if co_signer.is_signer
+                && self.is_council_member(co_signer.key)
+                && !approvals.contains(co_signer.key)
+            {
+                approvals.push(*co_signer.key);
+            }
+        }
+
+        // checking if enough council members approved the action
+        require!(
+            approvals.len() >= self.council_threshold.max(1) as usize,
+            ErrorCode::CouncilApprovalError
+        );
+
+        Ok(())
+    }
+
+    // replaces the council, every co-captain must be a member of the team other than the captain
+    pub fn set_council(&mut self, council: CouncilConfig) -> Result<()> {
+        council.validate()?;
+        require!(
+            council
+                .members
+                .iter()
+                .all(|member| self.members.contains(member) && *member != self.captain),
+            ErrorCode::InvalidCouncilError
+        );
+
+        self.council = council.members;
+        self.council_threshold = council.threshold;
+
+        Ok(())
+    }
+
+    // drops a member that left the team or became the captain from the council
+    // the threshold is lowered when the council gets smaller than it
+    pub fn remove_from_council(&mut self, member: &Pubkey) {
+        self.council.retain(|co_captain| co_captain != member);
+        self.council_threshold = self.council_threshold.min(self.council.len() as u8 + 1);
+    }
+}
@@ -0,0 +1,106 @@
+use super::errors::ErrorCode;
+use super::*;
+
+pub fn creating_proposal(
+    ctx: Context<CreateProposal>,
+    kind: ProposalKind,
+    payload: Vec<u8>,
+    voting_period: i64,
+) -> Result<()> {
+    let team = &mut ctx.accounts.team_account;
+
+    // checking if the signer is in the team
+    require!(
+        team.members.contains(ctx.accounts.signer.key),
+        ErrorCode::MemberNotInTeamError
+    );
+    // checking if the signer can create proposals of this kind
+    team.require_permission(ctx.accounts.signer.key, kind.create_permission())?;
+    // checking if the voting period is positive and not longer than the max voting period
+    require!(
+        voting_period > 0 && voting_period <= ProposalAccount::MAX_VOTING_PERIOD,
+        ErrorCode::InvalidVotingPeriodError
+    );
+    // checking if the payload is valid for the proposal kind
+    kind.validate_payload(&payload)?;
+    // checking if the split is not changed after the team is registered, the prize follows the entry snapshot
+    require!(
+        kind != ProposalKind::Distribution || !team.is_registered_to_active_tournament(),
+        ErrorCode::RosterLockedError
+    );
+
+    let proposal = &mut ctx.accounts.proposal_account;
+
+    proposal.bump = *ctx
+        .bumps
+        .get("proposal_account")
+        .ok_or(ErrorCode::InvalidBumpSeeds)?;
+
+    // assigning required parameters to the proposal
+    proposal.team = team.key();
+    proposal.proposer = *ctx.accounts.signer.key;
+    proposal.id = team.proposal_count;
+    proposal.kind = kind;
+    proposal.payload = payload;
+    proposal.deadline = Clock::get()?
+        .unix_timestamp
+        .checked_add(voting_period)
+        .ok_or(ErrorCode::InvalidVotingPeriodError)?;
+    proposal.status = ProposalStatus::Active;
+
+    // the next proposal of the team gets the next id
+    team.proposal_count += 1;
+
+    match kind {
+        ProposalKind::JoinTournament => {
+            let (tournament, prize_mint) = decode_payload::<(Pubkey, Pubkey)>(&proposal.payload)?;
+            emit!(TournamentInitiated {
+                team: team.key(),
+                tournament,
+                prize_mint,
+                closes_at: proposal.deadline,
+            });
+        }
+        ProposalKind::Distribution => {
+            emit!(DistributionProposed {
+                team: team.key(),
+                shares: decode_payload::<Vec<DistributionShare>>(&proposal.payload)?,
+                closes_at: proposal.deadline,
+            });
+        }
+        _ => {}
+    }
+
+    emit!(ProposalCreated {
+        team: team.key(),
+        proposal: proposal.key(),
+        id: proposal.id,
+        proposer: proposal.proposer,
+        kind,
+        deadline: proposal.deadline,
+    });
+
+    msg!(
+        "Proposal {} is successfully created in the team {}",
+        proposal.id,
+        team.name
+    );
+
+    Ok(())
+}
+
+// create proposal instruction
+#[derive(Accounts)]
+#[instruction(_kind: ProposalKind, payload: Vec<u8>)]
+pub struct CreateProposal<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(init, payer = signer, space = ProposalAccount::space(payload.len(), team_account.max_roster_size), seeds=[b"proposal".as_ref(), team_account.key().as_ref(), &team_account.proposal_count.to_le_bytes()], bump)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
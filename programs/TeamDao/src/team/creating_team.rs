@@ -1,21 +1,125 @@
 use super::errors::ErrorCode;
 use super::*;
+use anchor_lang::system_program;
+
+pub fn creating_team(
+    ctx: Context<CreateTeam>,
+    team_name: String,
+    team_id: u64,
+    name_hash: [u8; 32],
+    governance: GovernanceConfig,
+    min_roster_size: u8,
+    max_roster_size: u8,
+    metadata: TeamMetadata,
+) -> Result<()> {
+    // checking if the name fits into the account
+    require!(
+        team_name.len() <= TeamAccount::MAX_NAME_LEN,
+        ErrorCode::InvalidTeamNameError
+    );
+    // checking if the roster size is valid
+    require!(
+        min_roster_size > 0
+            && min_roster_size <= max_roster_size
+            && max_roster_size <= TeamAccount::MAX_ROSTER_SIZE,
+        ErrorCode::InvalidRosterSizeError
+    );
+    // checking if the quorum and approval percentages are valid
+    governance.validate()?;
+    // checking if the metadata fits into the metadata account
+    metadata.validate()?;
+    // checking if the id is the next id of the registry
+    require!(
+        team_id == ctx.accounts.registry_account.team_count,
+        ErrorCode::InvalidTeamIdError
+    );
+    // checking if the name hash belongs to the name of the team
+    require!(
+        NameReservationAccount::hash_name(&team_name) == name_hash,
+        ErrorCode::InvalidNameHashError
+    );
 
-pub fn creating_team(ctx: Context<CreateTeam>, team_name: String, team_id: u64) -> Result<()> {
     let team = &mut ctx.accounts.team_account;
 
     team.bump = *ctx
         .bumps
         .get("team_account")
         .ok_or(ErrorCode::InvalidBumpSeeds)?;
+    team.vault_bump = *ctx.bumps.get("vault").ok_or(ErrorCode::InvalidBumpSeeds)?;
 
     // assigning required parameters to the team
+    team.version = TeamAccount::VERSION;
     team.name = team_name;
     team.captain = *ctx.accounts.signer.key;
     team.id = team_id;
     team.members.push(*ctx.accounts.signer.key);
     team.can_join_tournament = false;
     team.distribution_voting_result = false;
+    team.governance = governance;
+    team.min_roster_size = min_roster_size;
+    team.max_roster_size = max_roster_size;
+    // the captain alone approves the sensitive actions until the team elects a council
+    team.council_threshold = 1;
+    team.role_permissions = Role::default_role_permissions();
+
+    // the metadata is stored in a companion account of the team
+    let metadata_account = &mut ctx.accounts.metadata_account;
+    metadata_account.bump = *ctx
+        .bumps
+        .get("metadata_account")
+        .ok_or(ErrorCode::InvalidBumpSeeds)?;
+    metadata_account.team = team.key();
+    metadata_account.metadata = metadata;
+
+    // registering the team, the ids of the teams are assigned by the registry
+    ctx.accounts.registry_account.team_count += 1;
+
+    let name_reservation = &mut ctx.accounts.name_reservation_account;
+    name_reservation.bump = *ctx
+        .bumps
+        .get("name_reservation_account")
+        .ok_or(ErrorCode::InvalidBumpSeeds)?;
+    name_reservation.team = team.key();
+    name_reservation.name_hash = name_hash;
+    name_reservation.index = team_id;
+
+    let team_index = &mut ctx.accounts.team_index_account;
+    team_index.bump = *ctx
+        .bumps
+        .get("team_index_account")
+        .ok_or(ErrorCode::InvalidBumpSeeds)?;
+    team_index.team = team.key();
+
+    // funding the prize vault with the rent exempt minimum so it always exists
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let vault_lamports = ctx.accounts.vault.lamports();
+    if vault_lamports < rent_exempt_minimum {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            rent_exempt_minimum - vault_lamports,
+        )?;
+    }
+
+    emit!(TeamCreated {
+        team: team.key(),
+        name: team.name.clone(),
+        id: team.id,
+        captain: team.captain,
+        min_roster_size: team.min_roster_size,
+        max_roster_size: team.max_roster_size,
+    });
+
+    emit!(TeamRegistered {
+        team: team.key(),
+        name: team.name.clone(),
+        index: team_id,
+    });
 
     msg!("Team created");
     msg!("Team name: {}", team.name);
@@ -26,11 +130,30 @@ pub fn creating_team(ctx: Context<CreateTeam>, team_name: String, team_id: u64)
 
 // derive macro for create team instruction
 #[derive(Accounts)]
-#[instruction(_team_name: String, _team_id: u64)]
+#[instruction(_team_name: String, _team_id: u64, name_hash: [u8; 32], _governance: GovernanceConfig, _min_roster_size: u8, max_roster_size: u8)]
 pub struct CreateTeam<'info> {
-    #[account(init, payer = signer, space = TeamAccount::LEN, seeds=[_team_name.as_bytes(), &_team_id.to_ne_bytes()], bump)]
+    #[account(init, payer = signer, space = TeamAccount::space(max_roster_size), seeds=[_team_name.as_bytes(), &_team_id.to_le_bytes()], bump)]
     pub team_account: Account<'info, TeamAccount>,
 
+    // prize vault of the team, holds the lamports paid out by claim reward
+    #[account(mut, seeds=[b"vault", team_account.key().as_ref()], bump)]
+    pub vault: SystemAccount<'info>,
+
+    // tag, logo, game and region of the team
+    #[account(init, payer = signer, space = TeamMetadataAccount::space(), seeds=[b"metadata", team_account.key().as_ref()], bump)]
+    pub metadata_account: Account<'info, TeamMetadataAccount>,
+
+    // assigns the id of the team
+    #[account(mut, seeds=[b"registry"], bump = registry_account.bump)]
+    pub registry_account: Account<'info, RegistryAccount>,
+
+    // fails to init if another team already uses the name
+    #[account(init, payer = signer, space = NameReservationAccount::LEN, seeds=[b"name", name_hash.as_ref()], bump)]
+    pub name_reservation_account: Account<'info, NameReservationAccount>,
+
+    #[account(init, payer = signer, space = TeamIndexAccount::LEN, seeds=[b"team_index".as_ref(), &_team_id.to_le_bytes()], bump)]
+    pub team_index_account: Account<'info, TeamIndexAccount>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
 
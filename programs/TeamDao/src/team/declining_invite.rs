@@ -0,0 +1,37 @@
+use super::*;
+
+pub fn declining_invite(ctx: Context<DeclineInvite>) -> Result<()> {
+    // the invitation is closed without adding the member
+    emit!(InvitationClosed {
+        team: ctx.accounts.team_account.key(),
+        invitee: *ctx.accounts.invitee.key,
+        accepted: false,
+    });
+
+    msg!(
+        "{} declined the invitation to the team {}",
+        ctx.accounts.invitee.key,
+        ctx.accounts.team_account.name
+    );
+
+    Ok(())
+}
+
+// decline invite instruction, signed by the invitee
+// the rent of the invitation goes back to the inviter
+#[derive(Accounts)]
+pub struct DeclineInvite<'info> {
+    #[account(seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut, seeds=[b"invite", team_account.key().as_ref(), invitee.key().as_ref()], bump = invitation_account.bump, has_one = inviter, close = inviter)]
+    pub invitation_account: Account<'info, InvitationAccount>,
+
+    #[account(mut)]
+    pub inviter: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub invitee: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
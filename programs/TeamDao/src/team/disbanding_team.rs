@@ -0,0 +1,82 @@
+use super::errors::ErrorCode;
+use super::*;
+use anchor_lang::system_program;
+
+pub fn disbanding_team(ctx: Context<DisbandTeam>) -> Result<()> {
+    let team = &ctx.accounts.team_account;
+
+    // checking if the signer is the captain
+    require!(
+        team.captain == *ctx.accounts.signer.key,
+        ErrorCode::NotCaptainError
+    );
+    // checking if the council approved the disband
+    team.check_council_approval(ctx.accounts.signer.key, ctx.remaining_accounts)?;
+    // checking if the team is not in a tournament
+    require!(
+        team.active_tournament == Pubkey::default(),
+        ErrorCode::ActiveTournamentError
+    );
+    // checking if the members claimed their share of the prize
+    require!(unclaimed_reward(team) == 0, ErrorCode::UnclaimedPrizeError);
+    // checking if the token vault of a token prize is swept and closed with close token vault
+    require!(
+        team.prize_mint == Pubkey::default(),
+        ErrorCode::TokenVaultNotClosedError
+    );
+
+    // sweeping the vault to the captain, including the rent reserve and the rounding dust
+    let team_key = team.key();
+    let vault_lamports = ctx.accounts.vault.lamports();
+    let vault_seeds: &[&[u8]] = &[b"vault".as_ref(), team_key.as_ref(), &[team.vault_bump]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.signer.to_account_info(),
+            },
+            &[vault_seeds],
+        ),
+        vault_lamports,
+    )?;
+
+    emit!(TeamDisbanded {
+        team: team_key,
+        captain: team.captain,
+        vault_lamports,
+    });
+
+    // the team account is closed and its rent goes back to the captain
+    msg!("{} is successfully disbanded", team.name);
+
+    Ok(())
+}
+
+// disband team instruction, closes the team account
+#[derive(Accounts)]
+pub struct DisbandTeam<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump, close = signer)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    // prize vault of the team, swept to the captain
+    #[account(mut, seeds=[b"vault", team_account.key().as_ref()], bump = team_account.vault_bump)]
+    pub vault: SystemAccount<'info>,
+
+    // metadata of the team, closed with the team account
+    #[account(mut, seeds=[b"metadata", team_account.key().as_ref()], bump = metadata_account.bump, close = signer)]
+    pub metadata_account: Account<'info, TeamMetadataAccount>,
+
+    // frees the name of the team
+    #[account(mut, seeds=[b"name", name_reservation_account.name_hash.as_ref()], bump = name_reservation_account.bump, constraint = name_reservation_account.team == team_account.key(), close = signer)]
+    pub name_reservation_account: Account<'info, NameReservationAccount>,
+
+    // removes the team from the registry, the index itself is not reused
+    #[account(mut, seeds=[b"team_index".as_ref(), &name_reservation_account.index.to_le_bytes()], bump = team_index_account.bump, close = signer)]
+    pub team_index_account: Account<'info, TeamIndexAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,126 @@
+use super::errors::ErrorCode;
+use super::*;
+use anchor_lang::system_program;
+
+pub fn distributing_rewards<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributeRewards<'info>>,
+) -> Result<()> {
+    let team = &ctx.accounts.team_account;
+
+    // checking if the prize of the tournament is paid in sol
+    // token prizes are claimed by every member with claim token reward
+    require!(
+        team.prize_mint == Pubkey::default(),
+        ErrorCode::InvalidPrizeMintError
+    );
+    // checking if the prize is released into the vault by the tournament
+    require!(team.result_reported, ErrorCode::ResultNotReportedError);
+    // checking if the prize is not settled yet
+    require!(!team.prize_settled, ErrorCode::PrizeSettledError);
+
+    // unclaimed reward of every member in the order of the accepted distribution
+    let owed: Vec<(Pubkey, u64)> = team
+        .prize_shares()
+        .iter()
+        .map(|share| {
+            let reward = team.reward_of(&share.member);
+            (
+                share.member,
+                reward.saturating_sub(claimed_reward_of(team, &share.member)),
+            )
+        })
+        .collect();
+
+    // checking if the member accounts are passed in the order of the distribution
+    require!(
+        ctx.remaining_accounts.len() == owed.len()
+            && ctx
+                .remaining_accounts
+                .iter()
+                .zip(owed.iter())
+                .all(|(account, (member, _))| account.key == member && account.is_writable),
+        ErrorCode::InvalidMemberAccountsError
+    );
+
+    // the vault pays at most its available lamports, the rewards are scaled down if it holds less
+    let total_owed: u64 = owed.iter().map(|(_, amount)| amount).sum();
+    let available = vault_available_lamports(&ctx.accounts.vault.to_account_info())?;
+    let pool = available.min(total_owed);
+
+    let team = &mut ctx.accounts.team_account;
+    let team_key = team.key();
+    let vault_seeds: &[&[u8]] = &[b"vault".as_ref(), team_key.as_ref(), &[team.vault_bump]];
+    let mut distributed: u64 = 0;
+
+    for ((member, amount_owed), account) in owed.iter().zip(ctx.remaining_accounts.iter()) {
+        if *amount_owed == 0 {
+            continue;
+        }
+
+        // rounded down, the remainder stays in the vault
+        let reward = (*amount_owed as u128 * pool as u128 / total_owed as u128) as u64;
+        if reward == 0 {
+            continue;
+        }
+
+        record_claim(team, member, reward)?;
+
+        // paying the reward from the team vault, the vault pda signs the transfer
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: account.clone(),
+                },
+                &[vault_seeds],
+            ),
+            reward,
+        )?;
+        distributed += reward;
+
+        emit!(RewardClaimed {
+            team: team_key,
+            member: *member,
+            amount: reward,
+            mint: Pubkey::default(),
+        });
+    }
+
+    // the prize is settled, the rounding dust stays in the vault as the treasury of the team
+    team.prize_settled = true;
+    // the tournament is over for the team, releasing it keeps the snapshot and the prize
+    team.clear_active_tournament();
+
+    emit!(RewardsDistributed {
+        team: team_key,
+        tournament: team.entry_snapshot.tournament,
+        distributed,
+        dust: pool - distributed,
+    });
+
+    msg!(
+        "{} lamports are distributed to the members of {}",
+        distributed,
+        team.name
+    );
+
+    Ok(())
+}
+
+// distribute the sol rewards of every member, can be called by anyone
+// the accounts of the members are passed as remaining accounts in the order of the distribution
+#[derive(Accounts)]
+pub struct DistributeRewards<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    // prize vault of the team, the rewards are paid only from here
+    #[account(mut, seeds=[b"vault", team_account.key().as_ref()], bump = team_account.vault_bump)]
+    pub vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,60 @@
+use super::errors::ErrorCode;
+use super::*;
+
+// share of the prize of a member in basis points, 10000 basis points are the whole prize
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct DistributionShare {
+    pub member: Pubkey,
+    pub basis_points: u16,
+}
+
+impl DistributionShare {
+    pub const TOTAL_BASIS_POINTS: u16 = 10_000;
+
+    // checks that the shares cover the whole prize, the membership is checked against the team
+    pub fn validate(shares: &[DistributionShare]) -> Result<()> {
+        let sum: u32 = shares.iter().map(|share| share.basis_points as u32).sum();
+        require!(
+            sum == Self::TOTAL_BASIS_POINTS as u32,
+            ErrorCode::InvalidPercentageError
+        );
+
+        Ok(())
+    }
+}
+
+impl TeamAccount {
+    // checks that every share belongs to a distinct member of the current roster
+    pub fn validate_distribution(&self, shares: &[DistributionShare]) -> Result<()> {
+        DistributionShare::validate(shares)?;
+
+        for (i, share) in shares.iter().enumerate() {
+            // checking if the member is in the team
+            require!(
+                self.members.contains(&share.member),
+                ErrorCode::MemberNotInTeamError
+            );
+            // checking if the member has only one share
+            require!(
+                !shares[..i].iter().any(|other| other.member == share.member),
+                ErrorCode::InvalidPercentageError
+            );
+        }
+
+        Ok(())
+    }
+
+    // basis points of the prize the member gets with the accepted distribution
+    pub fn basis_points_of(&self, member: &Pubkey) -> u16 {
+        self.prize_shares()
+            .iter()
+            .find(|share| share.member == *member)
+            .map_or(0, |share| share.basis_points)
+    }
+
+    // part of the prize the member gets, rounded down
+    pub fn reward_of(&self, member: &Pubkey) -> u64 {
+        (self.prize as u128 * self.basis_points_of(member) as u128
+            / DistributionShare::TOTAL_BASIS_POINTS as u128) as u64
+    }
+}
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum ErrorCode {
-    #[msg("A team can contain maximum 5 members")]
+    #[msg("The team reached its maximum roster size")]
     TeamCapacityFullError,
     #[msg("Invalid bump seeds")]
     InvalidBumpSeeds,
@@ -22,10 +22,114 @@ pub enum ErrorCode {
     AlreadyActiveTournamentError,
     #[msg("The team has no active tournament")]
     NoActiveTournamentError,
-    #[msg("A team must contain 5 players to join a tournament")]
+    #[msg("The lineup of the team must have as many players as the roster size of the tournament")]
     NotEnoughPlayersError,
-    #[msg("The sum of percentages must be equal to 100")]
+    #[msg(
+        "The distribution shares must belong to distinct members and sum up to 10000 basis points"
+    )]
     InvalidPercentageError,
     #[msg("Invalid member for that reward")]
     InvalidRewardError,
+    #[msg("The team vault does not hold enough lamports for the tournament prize")]
+    VaultNotFundedError,
+    #[msg("The prize mint does not match the prize mint of the active tournament")]
+    InvalidPrizeMintError,
+    #[msg("The reward exceeds the unclaimed share of the member")]
+    RewardAlreadyClaimedError,
+    #[msg("Only the organizer of the tournament can call this function")]
+    NotOrganizerError,
+    #[msg("The tournament name can contain maximum 32 bytes")]
+    InvalidTournamentNameError,
+    #[msg("The tournament must end after it starts and accept between 1 and 128 teams")]
+    InvalidTournamentScheduleError,
+    #[msg("The tournament is not in the required status for this function")]
+    InvalidTournamentStatusError,
+    #[msg("The tournament has not ended yet")]
+    TournamentNotEndedError,
+    #[msg("The tournament account does not match the active tournament of the team")]
+    InvalidTournamentError,
+    #[msg("The tournament has no room for another team")]
+    TournamentFullError,
+    #[msg("The team is already registered to the tournament")]
+    TeamAlreadyRegisteredError,
+    #[msg("Only the organizer or the oracle of the tournament can report results")]
+    NotResultReporterError,
+    #[msg("The team is not registered to the tournament")]
+    TeamNotRegisteredError,
+    #[msg("The result of the team is already reported")]
+    ResultAlreadyReportedError,
+    #[msg("The prize exceeds the remaining prize pool of the tournament")]
+    InsufficientPrizePoolError,
+    #[msg("The result of the tournament is not reported yet")]
+    ResultNotReportedError,
+    #[msg("The voting period must be positive and at most 30 days")]
+    InvalidVotingPeriodError,
+    #[msg("The payload is not valid for the proposal kind")]
+    InvalidProposalPayloadError,
+    #[msg("The proposal is not open for votes")]
+    ProposalNotActiveError,
+    #[msg("The voting period of the proposal is over")]
+    ProposalExpiredError,
+    #[msg("Only passed proposals can be executed")]
+    ProposalNotPassedError,
+    #[msg("There is no open vote for this topic")]
+    VoteNotOpenError,
+    #[msg("The voting period is over, the vote must be finalized")]
+    VoteExpiredError,
+    #[msg("The voting period is not over yet")]
+    VoteNotExpiredError,
+    #[msg("Quorum and approval percentages must be between 1 and 100")]
+    InvalidGovernanceConfigError,
+    #[msg("Roster size must be between 1 and 12 and the minimum cannot exceed the maximum")]
+    InvalidRosterSizeError,
+    #[msg("Team name is too long")]
+    InvalidTeamNameError,
+    #[msg("The roster size of the tournament does not fit the format of the team")]
+    RosterSizeMismatchError,
+    #[msg("The proposal kind does not match this instruction")]
+    InvalidProposalKindError,
+    #[msg("The last member of the team cannot leave, disband the team instead")]
+    LastMemberError,
+    #[msg("The team cannot be disbanded while it has an active tournament")]
+    ActiveTournamentError,
+    #[msg("The prize of the team is not fully claimed")]
+    UnclaimedPrizeError,
+    #[msg("The account is not a team account of the program")]
+    InvalidTeamAccountError,
+    #[msg("The team account is already migrated")]
+    AlreadyMigratedError,
+    #[msg("A metadata field of the team is too long")]
+    InvalidTeamMetadataError,
+    #[msg("The team id must be the next id of the registry")]
+    InvalidTeamIdError,
+    #[msg("The name hash does not match the name of the team")]
+    InvalidNameHashError,
+    #[msg("Not enough council members approved the action")]
+    CouncilApprovalError,
+    #[msg("The council must be at most 4 co-captains of the team and the threshold must be between 1 and the council size")]
+    InvalidCouncilError,
+    #[msg("The member does not have the permission for this action")]
+    PermissionDeniedError,
+    #[msg("The captain and the co-captain roles cannot be assigned or changed")]
+    InvalidRoleError,
+    #[msg("The permissions contain unknown bits")]
+    InvalidPermissionsError,
+    #[msg("The lineup must have as many distinct members as the roster size of the tournament")]
+    InvalidLineupError,
+    #[msg("The roster of the team is locked until the tournament is settled")]
+    RosterLockedError,
+    #[msg("The prize of the tournament is already settled")]
+    PrizeSettledError,
+    #[msg("The member accounts must be passed in the order of the distribution shares")]
+    InvalidMemberAccountsError,
+    #[msg("The execution period of the proposal is over")]
+    ProposalExecutionExpiredError,
+    #[msg("The token vault of the team must be closed before the team is disbanded")]
+    TokenVaultNotClosedError,
+    #[msg("The team is registered to the tournament and cannot leave it before its result is reported")]
+    RegisteredTournamentError,
+    #[msg("The tournament has already started")]
+    TournamentStartedError,
+    #[msg("The result of every registered team must be reported first")]
+    ResultsPendingError,
 }
@@ -0,0 +1,220 @@
+use super::*;
+
+// events emitted by the team instructions, used by indexers and bots
+// every event carries the team account so it can be filtered per team
+
+#[event]
+pub struct TeamCreated {
+    pub team: Pubkey,
+    pub name: String,
+    pub id: u64,
+    pub captain: Pubkey,
+    pub min_roster_size: u8,
+    pub max_roster_size: u8,
+}
+
+#[event]
+pub struct TeamMetadataUpdated {
+    pub team: Pubkey,
+}
+
+#[event]
+pub struct TeamMigrated {
+    pub team: Pubkey,
+    pub version: u8,
+}
+
+#[event]
+pub struct MemberInvited {
+    pub team: Pubkey,
+    pub invitee: Pubkey,
+    pub inviter: Pubkey,
+}
+
+#[event]
+pub struct InvitationClosed {
+    pub team: Pubkey,
+    pub invitee: Pubkey,
+    // true if the invitee accepted the invitation
+    pub accepted: bool,
+}
+
+#[event]
+pub struct JoinRequested {
+    pub team: Pubkey,
+    pub applicant: Pubkey,
+}
+
+#[event]
+pub struct JoinRequestClosed {
+    pub team: Pubkey,
+    pub applicant: Pubkey,
+    // true if the applicant is added to the team
+    pub approved: bool,
+}
+
+#[event]
+pub struct OpenToJoinChanged {
+    pub team: Pubkey,
+    pub open_to_join: bool,
+}
+
+#[event]
+pub struct MemberAdded {
+    pub team: Pubkey,
+    pub member: Pubkey,
+}
+
+#[event]
+pub struct MemberRemoved {
+    pub team: Pubkey,
+    pub member: Pubkey,
+    // true if the member left the team by themselves
+    pub left: bool,
+}
+
+#[event]
+pub struct CaptainTransferred {
+    pub team: Pubkey,
+    pub old_captain: Pubkey,
+    pub new_captain: Pubkey,
+}
+
+#[event]
+pub struct TeamDisbanded {
+    pub team: Pubkey,
+    pub captain: Pubkey,
+    // lamports swept from the vault to the captain
+    pub vault_lamports: u64,
+}
+
+#[event]
+pub struct TokenVaultClosed {
+    pub team: Pubkey,
+    pub mint: Pubkey,
+    // tokens swept from the token vault to the captain
+    pub amount: u64,
+}
+
+#[event]
+pub struct TournamentInitiated {
+    pub team: Pubkey,
+    pub tournament: Pubkey,
+    pub prize_mint: Pubkey,
+    // deadline of the join tournament proposal
+    pub closes_at: i64,
+}
+
+#[event]
+pub struct TournamentLeft {
+    pub team: Pubkey,
+    pub tournament: Pubkey,
+}
+
+#[event]
+pub struct DistributionProposed {
+    pub team: Pubkey,
+    pub shares: Vec<DistributionShare>,
+    // deadline of the distribution proposal
+    pub closes_at: i64,
+}
+
+#[event]
+pub struct TournamentJoined {
+    pub team: Pubkey,
+    pub tournament: Pubkey,
+    pub entry_fee: u64,
+}
+
+#[event]
+pub struct RewardClaimed {
+    pub team: Pubkey,
+    pub member: Pubkey,
+    pub amount: u64,
+    // default pubkey for rewards paid in sol
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub team: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub kind: ProposalKind,
+    pub deadline: i64,
+}
+
+#[event]
+pub struct ProposalVoteCast {
+    pub team: Pubkey,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub vote_type: VoteType,
+    pub status: ProposalStatus,
+}
+
+#[event]
+pub struct ProposalFinalized {
+    pub team: Pubkey,
+    pub proposal: Pubkey,
+    pub status: ProposalStatus,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub team: Pubkey,
+    pub proposal: Pubkey,
+    pub kind: ProposalKind,
+}
+
+#[event]
+pub struct TeamRegistered {
+    pub team: Pubkey,
+    pub name: String,
+    pub index: u64,
+}
+
+#[event]
+pub struct CouncilUpdated {
+    pub team: Pubkey,
+    pub council: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MemberRoleChanged {
+    pub team: Pubkey,
+    pub member: Pubkey,
+    pub role: Role,
+}
+
+#[event]
+pub struct RolePermissionsChanged {
+    pub team: Pubkey,
+    pub role: Role,
+    pub permissions: u16,
+}
+
+#[event]
+pub struct LineupSet {
+    pub team: Pubkey,
+    pub tournament: Pubkey,
+    pub lineup: Vec<Pubkey>,
+}
+
+#[event]
+pub struct PlayerSubstituted {
+    pub team: Pubkey,
+    pub tournament: Pubkey,
+    pub player_out: Pubkey,
+    pub player_in: Pubkey,
+}
+
+#[event]
+pub struct RewardsDistributed {
+    pub team: Pubkey,
+    pub tournament: Pubkey,
+    pub distributed: u64,
+    pub dust: u64,
+}
@@ -0,0 +1,170 @@
+use super::errors::ErrorCode;
+use super::*;
+
+pub fn executing_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let team = &mut ctx.accounts.team_account;
+    let proposal = &mut ctx.accounts.proposal_account;
+
+    // checking if the proposal is passed, not executed yet and not expired
+    proposal.require_executable(Clock::get()?.unix_timestamp)?;
+
+    // applying the proposal to the team
+    match proposal.kind {
+        ProposalKind::JoinTournament => {
+            // checking if the council approved the tournament
+            team.check_council_approval(ctx.accounts.signer.key, ctx.remaining_accounts)?;
+            // checking if the team has already an active tournament
+            require!(
+                team.active_tournament == Pubkey::default(),
+                ErrorCode::AlreadyActiveTournamentError
+            );
+
+            // the prize is released into the vault or deposited to the token vault
+            // when the tournament reports the result
+            let (tournament_address, prize_mint) =
+                decode_payload::<(Pubkey, Pubkey)>(&proposal.payload)?;
+            team.set_active_tournament(tournament_address, 0, prize_mint)?;
+        }
+        ProposalKind::LeaveTournament => {
+            // checking if the team has an active tournament
+            require!(
+                team.active_tournament != Pubkey::default(),
+                ErrorCode::NoActiveTournamentError
+            );
+            // checking if the team is not registered, a registered team stays until its result is reported
+            require!(
+                !team.is_registered_to_active_tournament() || team.result_reported,
+                ErrorCode::RegisteredTournamentError
+            );
+
+            let tournament = team.active_tournament;
+            team.clear_active_tournament();
+
+            emit!(TournamentLeft {
+                team: team.key(),
+                tournament,
+            });
+        }
+        ProposalKind::Distribution => {
+            // checking if the team has an active tournament
+            require!(
+                team.active_tournament != Pubkey::default(),
+                ErrorCode::NoActiveTournamentError
+            );
+
+            // checking if the council approved the distribution
+            team.check_council_approval(ctx.accounts.signer.key, ctx.remaining_accounts)?;
+
+            // checking if the team is not registered yet, the prize follows the entry snapshot
+            require!(
+                !team.is_registered_to_active_tournament(),
+                ErrorCode::RosterLockedError
+            );
+
+            let shares = decode_payload::<Vec<DistributionShare>>(&proposal.payload)?;
+            // checking if the shares belong to the members of the team at the execution
+            team.validate_distribution(&shares)?;
+            team.distribution_shares = shares;
+            team.distribution_voting_result = true;
+        }
+        ProposalKind::UpdateGovernance => {
+            team.governance = decode_payload::<GovernanceConfig>(&proposal.payload)?;
+        }
+        ProposalKind::ApproveJoinRequest => {
+            // the join request is closed by its own instruction
+            return err!(ErrorCode::InvalidProposalKindError);
+        }
+        ProposalKind::UpdateCouncil => {
+            team.set_council(decode_payload::<CouncilConfig>(&proposal.payload)?)?;
+
+            emit!(CouncilUpdated {
+                team: team.key(),
+                council: team.council.clone(),
+                threshold: team.council_threshold,
+            });
+        }
+        ProposalKind::SetMemberRole => {
+            let (member, role) = decode_payload::<(Pubkey, Role)>(&proposal.payload)?;
+            team.set_member_role(member, role)?;
+
+            emit!(MemberRoleChanged {
+                team: team.key(),
+                member,
+                role,
+            });
+        }
+        ProposalKind::SetRolePermissions => {
+            let (role, permissions) = decode_payload::<(Role, u16)>(&proposal.payload)?;
+            team.set_role_permissions(role, permissions)?;
+
+            emit!(RolePermissionsChanged {
+                team: team.key(),
+                role,
+                permissions,
+            });
+        }
+        ProposalKind::EmergencySubstitution => {
+            // checking if the team has a lineup for its active tournament
+            require!(
+                team.active_tournament != Pubkey::default()
+                    && team.lineup_tournament == team.active_tournament,
+                ErrorCode::NoActiveTournamentError
+            );
+
+            let (player_out, player_in) = decode_payload::<(Pubkey, Pubkey)>(&proposal.payload)?;
+            // an outside player joins the roster in place of the outgoing player
+            let joins_roster = !team.members.contains(&player_in);
+            team.substitute_player(player_out, player_in)?;
+
+            if joins_roster {
+                emit!(MemberRemoved {
+                    team: team.key(),
+                    member: player_out,
+                    left: false,
+                });
+                emit!(MemberAdded {
+                    team: team.key(),
+                    member: player_in,
+                });
+            }
+
+            emit!(PlayerSubstituted {
+                team: team.key(),
+                tournament: team.active_tournament,
+                player_out,
+                player_in,
+            });
+        }
+    }
+
+    proposal.status = ProposalStatus::Executed;
+
+    emit!(ProposalExecuted {
+        team: team.key(),
+        proposal: proposal.key(),
+        kind: proposal.kind,
+    });
+
+    msg!(
+        "Proposal {} is successfully executed in the team {}",
+        proposal.id,
+        team.name
+    );
+
+    Ok(())
+}
+
+// execute proposal instruction
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut, seeds=[b"proposal".as_ref(), team_account.key().as_ref(), &proposal_account.id.to_le_bytes()], bump = proposal_account.bump)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,51 @@
+use super::errors::ErrorCode;
+use super::*;
+
+pub fn finalizing_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+    let team = &ctx.accounts.team_account;
+    let proposal = &mut ctx.accounts.proposal_account;
+
+    // checking if the proposal is still open for votes
+    require!(
+        proposal.status == ProposalStatus::Active,
+        ErrorCode::ProposalNotActiveError
+    );
+    // checking if the voting period is over
+    require!(
+        Clock::get()?.unix_timestamp > proposal.deadline,
+        ErrorCode::VoteNotExpiredError
+    );
+
+    // an expired proposal passes if the votes cast in time reach the quorum and the approval
+    let team_size = team.count_proposal_voters(proposal.kind);
+    proposal.finalize_status(&team.governance, team_size);
+
+    emit!(ProposalFinalized {
+        team: team.key(),
+        proposal: proposal.key(),
+        status: proposal.status,
+    });
+
+    msg!(
+        "The expired proposal {} of the team {} is finalized",
+        proposal.id,
+        team.name
+    );
+
+    Ok(())
+}
+
+// finalize an expired proposal, can be called by anyone
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    #[account(seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut, seeds=[b"proposal".as_ref(), team_account.key().as_ref(), &proposal_account.id.to_le_bytes()], bump = proposal_account.bump)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
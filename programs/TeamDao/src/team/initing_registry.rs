@@ -0,0 +1,28 @@
+use super::errors::ErrorCode;
+use super::*;
+
+pub fn initing_registry(ctx: Context<InitRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry_account;
+
+    registry.bump = *ctx
+        .bumps
+        .get("registry_account")
+        .ok_or(ErrorCode::InvalidBumpSeeds)?;
+    registry.team_count = 0;
+
+    msg!("Team registry is initialized");
+
+    Ok(())
+}
+
+// init registry instruction, called once after the program is deployed
+#[derive(Accounts)]
+pub struct InitRegistry<'info> {
+    #[account(init, payer = signer, space = RegistryAccount::LEN, seeds=[b"registry"], bump)]
+    pub registry_account: Account<'info, RegistryAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
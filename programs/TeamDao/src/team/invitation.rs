@@ -0,0 +1,19 @@
+use super::*;
+
+// Invitation account struct, a pending invitation of the captain to join the team
+// the invitee becomes a member only after accepting it
+#[account]
+pub struct InvitationAccount {
+    pub team: Pubkey,
+    pub inviter: Pubkey,
+    pub invitee: Pubkey,
+    pub bump: u8,
+}
+
+impl InvitationAccount {
+    pub const LEN: usize = 8 // discriminator
+    + 32 // team pubkey
+    + 32 // inviter pubkey
+    + 32 // invitee pubkey
+    + 1; // bump
+}
@@ -0,0 +1,61 @@
+use super::errors::ErrorCode;
+use super::*;
+
+pub fn inviting_member(ctx: Context<InviteMember>, member: Pubkey) -> Result<()> {
+    let team = &ctx.accounts.team_account;
+
+    // checking if the signer can invite members
+    team.require_permission(ctx.accounts.signer.key, Permission::INVITE_MEMBER)?;
+    // checking if the council approved the invitation
+    team.check_council_approval(ctx.accounts.signer.key, ctx.remaining_accounts)?;
+    // checking if the team already has the maximum number of players if so, return error
+    require!(
+        team.members.len() < team.max_roster_size as usize,
+        ErrorCode::TeamCapacityFullError
+    );
+    // checking if the member is already in the team, if so, return error
+    require!(
+        !team.members.contains(&member),
+        ErrorCode::MemberAlreadyInTeamError
+    );
+
+    let invitation = &mut ctx.accounts.invitation_account;
+
+    invitation.bump = *ctx
+        .bumps
+        .get("invitation_account")
+        .ok_or(ErrorCode::InvalidBumpSeeds)?;
+    invitation.team = team.key();
+    invitation.inviter = *ctx.accounts.signer.key;
+    invitation.invitee = member;
+
+    emit!(MemberInvited {
+        team: team.key(),
+        invitee: member,
+        inviter: invitation.inviter,
+    });
+
+    msg!(
+        "{} is successfully invited to the team {}",
+        member,
+        team.name
+    );
+
+    Ok(())
+}
+
+// derive macro for invite member instruction
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct InviteMember<'info> {
+    #[account(seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(init, payer = signer, space = InvitationAccount::LEN, seeds=[b"invite", team_account.key().as_ref(), member.as_ref()], bump)]
+    pub invitation_account: Account<'info, InvitationAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
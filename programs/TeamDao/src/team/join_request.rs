@@ -0,0 +1,17 @@
+use super::*;
+
+// Join request account struct, a pending request of a player to join the team
+// the applicant becomes a member after the captain or a team vote approves it
+#[account]
+pub struct JoinRequestAccount {
+    pub team: Pubkey,
+    pub applicant: Pubkey,
+    pub bump: u8,
+}
+
+impl JoinRequestAccount {
+    pub const LEN: usize = 8 // discriminator
+    + 32 // team pubkey
+    + 32 // applicant pubkey
+    + 1; // bump
+}
@@ -1,7 +1,7 @@
 use super::errors::ErrorCode;
 use super::*;
 
-pub fn leaving_team(ctx: Context<LeaveTeam>, _team_name: String, _team_id: u64) -> Result<()> {
+pub fn leaving_team(ctx: Context<LeaveTeam>) -> Result<()> {
     let team = &mut ctx.accounts.team_account;
 
     // checking if the signer is in the team
@@ -10,21 +10,32 @@ pub fn leaving_team(ctx: Context<LeaveTeam>, _team_name: String, _team_id: u64)
         ErrorCode::MemberNotInTeamError
     );
 
-    if team.members.len() == 1 {
-        // if the captain is the last member disband team
-        // delete team
-        team.name = "".to_string();
-        team.captain = Pubkey::default();
-        team.id = 0;
-        team.members = vec![];
-    }
+    // checking if the roster is not locked by the tournament
+    team.require_roster_unlocked()?;
+
+    // the last member has to disband the team to close its account
+    require!(team.members.len() > 1, ErrorCode::LastMemberError);
+
     if team.captain == *ctx.accounts.signer.key {
-        // transfer captain role to the second member in the team
-        team.captain = team.members[1];
+        // transfer captain role to the first member in the team other than the captain
+        let new_captain = *team
+            .members
+            .iter()
+            .find(|member| *member != ctx.accounts.signer.key)
+            .ok_or(ErrorCode::LastMemberError)?;
+        team.captain = new_captain;
+        team.remove_from_council(&new_captain);
+        team.remove_member_role(&new_captain);
     }
 
     // deleting the member from team
-    team.members.retain(|&x| x != *ctx.accounts.signer.key);
+    team.remove_member(ctx.accounts.signer.key);
+
+    emit!(MemberRemoved {
+        team: team.key(),
+        member: *ctx.accounts.signer.key,
+        left: true,
+    });
 
     msg!(
         "{} is successfully removed from the team {}",
@@ -37,9 +48,8 @@ pub fn leaving_team(ctx: Context<LeaveTeam>, _team_name: String, _team_id: u64)
 
 // leave team instruction
 #[derive(Accounts)]
-#[instruction(team_name: String, team_id: u64)]
 pub struct LeaveTeam<'info> {
-    #[account(mut, seeds=[team_name.as_bytes(), &team_id.to_ne_bytes()], bump = team_account.bump)]
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
     pub team_account: Account<'info, TeamAccount>,
 
     #[account(mut)]
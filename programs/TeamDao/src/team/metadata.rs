@@ -0,0 +1,84 @@
+use super::errors::ErrorCode;
+use super::*;
+
+// Team metadata account struct, a companion pda of the team account
+// used by the matchmaking ui, the program does not read it
+#[account]
+#[derive(Default)]
+pub struct TeamMetadataAccount {
+    pub team: Pubkey,
+    pub bump: u8,
+    pub metadata: TeamMetadata,
+}
+
+impl TeamMetadataAccount {
+    // the space is computed from the metadata with every field at its maximum size
+    pub fn space() -> usize {
+        let max_uri = Some("x".repeat(TeamMetadata::MAX_URI_LEN));
+        let max_account = TeamMetadataAccount {
+            metadata: TeamMetadata {
+                tag: Some("x".repeat(TeamMetadata::MAX_TAG_LEN)),
+                logo_uri: max_uri.clone(),
+                banner_uri: max_uri,
+                game: Some("x".repeat(TeamMetadata::MAX_GAME_LEN)),
+                region: Some("x".repeat(TeamMetadata::MAX_REGION_LEN)),
+                social_links: vec![
+                    "x".repeat(TeamMetadata::MAX_URI_LEN);
+                    TeamMetadata::MAX_SOCIAL_LINKS
+                ],
+            },
+            ..Default::default()
+        };
+
+        // discriminator + borsh serialized metadata account
+        8 + max_account.try_to_vec().unwrap().len()
+    } // 910 bytes < 10k
+}
+
+// optional metadata of the team
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TeamMetadata {
+    // short tag shown next to the player names, e.g. "TL"
+    pub tag: Option<String>,
+    pub logo_uri: Option<String>,
+    pub banner_uri: Option<String>,
+    // identifier of the primary game of the team, e.g. "valorant"
+    pub game: Option<String>,
+    // region the team plays in, e.g. "eu-west"
+    pub region: Option<String>,
+    pub social_links: Vec<String>,
+}
+
+impl TeamMetadata {
+    pub const MAX_TAG_LEN: usize = 8;
+    pub const MAX_URI_LEN: usize = 128;
+    pub const MAX_GAME_LEN: usize = 32;
+    pub const MAX_REGION_LEN: usize = 16;
+    pub const MAX_SOCIAL_LINKS: usize = 4;
+
+    // checks if every field fits into the metadata account
+    pub fn validate(&self) -> Result<()> {
+        let fits = |field: &Option<String>, max_len: usize| {
+            field.as_ref().map_or(true, |value| value.len() <= max_len)
+        };
+
+        require!(
+            fits(&self.tag, Self::MAX_TAG_LEN)
+                && fits(&self.logo_uri, Self::MAX_URI_LEN)
+                && fits(&self.banner_uri, Self::MAX_URI_LEN)
+                && fits(&self.game, Self::MAX_GAME_LEN)
+                && fits(&self.region, Self::MAX_REGION_LEN),
+            ErrorCode::InvalidTeamMetadataError
+        );
+        require!(
+            self.social_links.len() <= Self::MAX_SOCIAL_LINKS
+                && self
+                    .social_links
+                    .iter()
+                    .all(|link| link.len() <= Self::MAX_URI_LEN),
+            ErrorCode::InvalidTeamMetadataError
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+use super::errors::ErrorCode;
+use super::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+// layout of the team account before the versioning, it has no version field
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyTeamAccount {
+    captain: Pubkey,
+    bump: u8,
+    name: String,
+    members: Vec<Pubkey>,
+    id: u64,
+    is_initialized: bool,
+    yes_votes: u8,
+    voted_players: Vec<Pubkey>,
+    active_tournament: Pubkey,
+    prize: u64,
+    voting_result: bool,
+    leave_votes: u8,
+    leave_voted_players: Vec<Pubkey>,
+    distribution_percentages: Vec<u8>,
+    distribution_yes_votes: u8,
+    distribution_voted_players: Vec<Pubkey>,
+    distribution_voting_result: bool,
+    can_join_tournament: bool,
+}
+
+impl LegacyTeamAccount {
+    // every legacy team account was allocated with this size
+    const LEN: usize = 612;
+    // legacy teams were limited to 5 players
+    const ROSTER_SIZE: u8 = 5;
+}
+
+pub fn migrating_team(
+    ctx: Context<MigrateTeam>,
+    _team_name: String,
+    _team_id: u64,
+    name_hash: [u8; 32],
+) -> Result<()> {
+    let info = ctx.accounts.team_account.to_account_info();
+
+    // checking if the account is a team account of the program
+    require!(
+        info.owner == ctx.program_id,
+        ErrorCode::InvalidTeamAccountError
+    );
+
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == TeamAccount::discriminator(),
+            ErrorCode::InvalidTeamAccountError
+        );
+        // checking if the account still has the legacy layout
+        require!(
+            data.len() == LegacyTeamAccount::LEN,
+            ErrorCode::AlreadyMigratedError
+        );
+
+        LegacyTeamAccount::deserialize(&mut &data[8..])
+            .map_err(|_| error!(ErrorCode::InvalidTeamAccountError))?
+    };
+    // checking if the name hash belongs to the name of the team
+    require!(
+        NameReservationAccount::hash_name(&legacy.name) == name_hash,
+        ErrorCode::InvalidNameHashError
+    );
+
+    let mut team = TeamAccount {
+        version: TeamAccount::VERSION,
+        captain: legacy.captain,
+        bump: legacy.bump,
+        name: legacy.name,
+        members: legacy.members.clone(),
+        id: legacy.id,
+        is_initialized: legacy.is_initialized,
+        // the team votes are proposals now, a tournament still in its vote is dropped
+        // and the team proposes it again
+        active_tournament: if legacy.voting_result {
+            legacy.active_tournament
+        } else {
+            Pubkey::default()
+        },
+        prize: legacy.prize,
+        // legacy percentages were indexed by the position of the member
+        distribution_shares: legacy
+            .members
+            .iter()
+            .zip(legacy.distribution_percentages.iter())
+            .map(|(member, percentage)| DistributionShare {
+                member: *member,
+                basis_points: *percentage as u16 * 100,
+            })
+            .collect(),
+        // a split still in its vote is kept but needs a distribution proposal to be accepted
+        distribution_voting_result: legacy.distribution_voting_result,
+        can_join_tournament: legacy.can_join_tournament,
+        vault_bump: *ctx.bumps.get("vault").ok_or(ErrorCode::InvalidBumpSeeds)?,
+        // legacy teams passed a vote with more than half of the 5 players
+        governance: GovernanceConfig {
+            quorum_percentage: 51,
+            approval_percentage: 51,
+        },
+        min_roster_size: LegacyTeamAccount::ROSTER_SIZE,
+        max_roster_size: LegacyTeamAccount::ROSTER_SIZE,
+        council_threshold: 1,
+        role_permissions: Role::default_role_permissions(),
+        ..Default::default()
+    };
+
+    // paying the rent of the grown account
+    let space = TeamAccount::space(team.max_roster_size);
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(space);
+    if info.lamports() < rent_exempt_minimum {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            rent_exempt_minimum - info.lamports(),
+        )?;
+    }
+
+    // growing the account and writing the team in the new layout
+    info.realloc(space, true)?;
+    {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        team.try_serialize(&mut writer)?;
+    }
+
+    // legacy teams have no vault, funding it with the rent exempt minimum like a new team
+    let vault_rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let vault_lamports = ctx.accounts.vault.lamports();
+    if vault_lamports < vault_rent_exempt_minimum {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            vault_rent_exempt_minimum - vault_lamports,
+        )?;
+    }
+
+    // legacy teams have no metadata, creating an empty one like a new team without metadata
+    let metadata_account = &mut ctx.accounts.metadata_account;
+    metadata_account.bump = *ctx
+        .bumps
+        .get("metadata_account")
+        .ok_or(ErrorCode::InvalidBumpSeeds)?;
+    metadata_account.team = info.key();
+
+    // registering the team, it keeps its id and the registry only assigns it an index
+    let registry = &mut ctx.accounts.registry_account;
+    let index = registry.team_count;
+    registry.team_count += 1;
+
+    let name_reservation = &mut ctx.accounts.name_reservation_account;
+    name_reservation.bump = *ctx
+        .bumps
+        .get("name_reservation_account")
+        .ok_or(ErrorCode::InvalidBumpSeeds)?;
+    name_reservation.team = info.key();
+    name_reservation.name_hash = name_hash;
+    name_reservation.index = index;
+
+    let team_index = &mut ctx.accounts.team_index_account;
+    team_index.bump = *ctx
+        .bumps
+        .get("team_index_account")
+        .ok_or(ErrorCode::InvalidBumpSeeds)?;
+    team_index.team = info.key();
+
+    emit!(TeamRegistered {
+        team: info.key(),
+        name: team.name.clone(),
+        index,
+    });
+
+    emit!(TeamMigrated {
+        team: info.key(),
+        version: team.version,
+    });
+
+    msg!(
+        "{} is successfully migrated to version {}",
+        team.name,
+        team.version
+    );
+
+    Ok(())
+}
+
+// migrate team instruction, can be called by anyone
+// the signer pays the rent of the grown account and of the accounts a new team gets in create team
+#[derive(Accounts)]
+#[instruction(_team_name: String, _team_id: u64, name_hash: [u8; 32])]
+pub struct MigrateTeam<'info> {
+    /// CHECK: the legacy layout cannot be loaded as a TeamAccount, the owner and the discriminator are checked in the handler
+    #[account(mut, seeds=[_team_name.as_bytes(), &_team_id.to_le_bytes()], bump)]
+    pub team_account: UncheckedAccount<'info>,
+
+    // prize vault of the team, created for the legacy teams
+    #[account(mut, seeds=[b"vault", team_account.key().as_ref()], bump)]
+    pub vault: SystemAccount<'info>,
+
+    // metadata of the team, legacy teams have none
+    #[account(init, payer = signer, space = TeamMetadataAccount::space(), seeds=[b"metadata", team_account.key().as_ref()], bump)]
+    pub metadata_account: Account<'info, TeamMetadataAccount>,
+
+    #[account(mut, seeds=[b"registry"], bump = registry_account.bump)]
+    pub registry_account: Account<'info, RegistryAccount>,
+
+    // fails to init if a new team already uses the name
+    #[account(init, payer = signer, space = NameReservationAccount::LEN, seeds=[b"name", name_hash.as_ref()], bump)]
+    pub name_reservation_account: Account<'info, NameReservationAccount>,
+
+    #[account(init, payer = signer, space = TeamIndexAccount::LEN, seeds=[b"team_index".as_ref(), &registry_account.team_count.to_le_bytes()], bump)]
+    pub team_index_account: Account<'info, TeamIndexAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,78 +1,372 @@
 use anchor_lang::prelude::*;
 
-pub mod adding_member;
+pub mod accepting_invite;
+pub mod approving_join_request;
 pub mod can_join;
+pub mod casting_vote;
 pub mod claiming_reward;
+pub mod closing_token_vault;
+pub mod council;
+pub mod creating_proposal;
 pub mod creating_team;
+pub mod declining_invite;
+pub mod disbanding_team;
+pub mod distributing_rewards;
+pub mod distribution;
 pub mod errors;
-pub mod handle_distribute_proposal;
-pub mod initing_percentage_proposal;
-pub mod initing_tournament;
+pub mod events;
+pub mod executing_proposal;
+pub mod finalizing_proposal;
+pub mod initing_registry;
+pub mod invitation;
+pub mod inviting_member;
+pub mod join_request;
 pub mod leaving_team;
-pub mod leaving_tournament;
+pub mod metadata;
+pub mod migrating_team;
+pub mod proposal;
+pub mod registry;
+pub mod rejecting_join_request;
 pub mod removing_member;
+pub mod requesting_to_join;
+pub mod revoking_invite;
+pub mod roles;
+pub mod setting_lineup;
+pub mod setting_member_role;
+pub mod setting_open_to_join;
+pub mod setting_role_permissions;
+pub mod snapshot;
 pub mod transfering_captain;
-pub mod voting_for_tournament;
+pub mod updating_team_metadata;
 
-pub use adding_member::*;
+pub use accepting_invite::*;
+pub use approving_join_request::*;
 pub use can_join::*;
+pub use casting_vote::*;
 pub use claiming_reward::*;
+pub use closing_token_vault::*;
+pub use council::*;
+pub use creating_proposal::*;
 pub use creating_team::*;
+pub use declining_invite::*;
+pub use disbanding_team::*;
+pub use distributing_rewards::*;
+pub use distribution::*;
 pub use errors::ErrorCode;
-pub use handle_distribute_proposal::*;
-pub use initing_percentage_proposal::*;
-pub use initing_tournament::*;
+pub use events::*;
+pub use executing_proposal::*;
+pub use finalizing_proposal::*;
+pub use initing_registry::*;
+pub use invitation::*;
+pub use inviting_member::*;
+pub use join_request::*;
 pub use leaving_team::*;
-pub use leaving_tournament::*;
+pub use metadata::*;
+pub use migrating_team::*;
+pub use proposal::*;
+pub use registry::*;
+pub use rejecting_join_request::*;
 pub use removing_member::*;
+pub use requesting_to_join::*;
+pub use revoking_invite::*;
+pub use roles::*;
+pub use setting_lineup::*;
+pub use setting_member_role::*;
+pub use setting_open_to_join::*;
+pub use setting_role_permissions::*;
+pub use snapshot::*;
 pub use transfering_captain::*;
-pub use voting_for_tournament::*;
+pub use updating_team_metadata::*;
 
 // Team account struct
 #[account]
+#[derive(Default)]
 pub struct TeamAccount {
+    // layout version of the account, the first field so it can be read from any layout
+    pub version: u8,
     pub captain: Pubkey,
     pub bump: u8,
     pub name: String,
     pub members: Vec<Pubkey>,
     pub id: u64,
     pub is_initialized: bool,
-    pub yes_votes: u8,
-    pub voted_players: Vec<Pubkey>,
+    // tournament joined with a passed proposal
     pub active_tournament: Pubkey,
     pub prize: u64,
-    pub voting_result: bool,
-    pub leave_votes: u8,
-    pub leave_voted_players: Vec<Pubkey>,
-    pub distribution_percentages: Vec<u8>,
-    pub distribution_yes_votes: u8,
-    pub distribution_voted_players: Vec<Pubkey>,
+    // shares of the prize per member in basis points, accepted with a passed proposal
+    pub distribution_shares: Vec<DistributionShare>,
+    // set when the shares are accepted for the active tournament
     pub distribution_voting_result: bool,
     pub can_join_tournament: bool,
+    pub vault_bump: u8,
+    pub prize_mint: Pubkey,
+    pub claimed_rewards: Vec<ClaimRecord>,
+    pub result_reported: bool,
+    pub proposal_count: u64,
+    pub governance: GovernanceConfig,
+    pub min_roster_size: u8,
+    pub max_roster_size: u8,
+    pub open_to_join: bool,
+    // co-captains of the team, the captain is not part of the list
+    pub council: Vec<Pubkey>,
+    // number of council members, the captain included, that must sign the sensitive actions
+    pub council_threshold: u8,
+    // roles of the members other than player, the captain and the co-captains are not listed
+    pub member_roles: Vec<MemberRole>,
+    // permission bits of every role indexed by the role
+    pub role_permissions: [u16; 6],
+    // starting players of the team for the lineup tournament, the other members are on the bench
+    pub lineup: Vec<Pubkey>,
+    pub lineup_tournament: Pubkey,
+    // set when the team is registered to the active tournament, the roster is frozen until settlement
+    pub roster_locked: bool,
+    // roster and distribution of the team at the registration to the tournament
+    pub entry_snapshot: EntrySnapshot,
+    // set when the rewards are distributed, the prize cannot be claimed anymore
+    pub prize_settled: bool,
 }
 
 impl TeamAccount {
-    const LEN: usize = 8 // discriminator 
-    + 32 // captain pubkey 
-    + 1 // bump 
-    + 32 // name
-    + 5 * 32 // members vector 
-    + 8 // id
-    + 1 // is_initialized
-    + 1 // yes_votes
-    + 5 * 32 // voted_players vector
-    + 32 // active_tournament
-    + 8 // tournament_prize
-    + 1 // voting_result
-    + 1 * 5 // reward_distribution_percentages vector
-    + 1 // distribution_yes_votes
-    + 5 * 32 // distribution_voted_players vector
-    + 1 // distribution_voting_result
-    + 1; // can_join_tournament
-} // 612 bytes < 10k
-
-#[derive(AnchorSerialize, AnchorDeserialize)]
+    pub const VERSION: u8 = 1;
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_ROSTER_SIZE: u8 = 12;
+
+    // the space is computed from a team with every field at its maximum size
+    // so it follows the struct when a field is added
+    pub fn space(max_roster_size: u8) -> usize {
+        let roster = max_roster_size as usize;
+        let max_team = TeamAccount {
+            name: "x".repeat(Self::MAX_NAME_LEN),
+            members: vec![Pubkey::default(); roster],
+            distribution_shares: vec![DistributionShare::default(); roster],
+            claimed_rewards: vec![ClaimRecord::default(); roster],
+            council: vec![Pubkey::default(); CouncilConfig::MAX_MEMBERS],
+            member_roles: vec![
+                MemberRole {
+                    member: Pubkey::default(),
+                    role: Role::Player,
+                };
+                roster
+            ],
+            lineup: vec![Pubkey::default(); roster],
+            entry_snapshot: EntrySnapshot {
+                tournament: Pubkey::default(),
+                members: vec![Pubkey::default(); roster],
+                shares: vec![DistributionShare::default(); roster],
+            },
+            ..Default::default()
+        };
+
+        // discriminator + borsh serialized team
+        8 + max_team.try_to_vec().unwrap().len()
+    } // 3259 bytes for 12 players < 10k
+
+    // adds a member that accepted an invitation or whose join request is approved
+    pub fn add_member(&mut self, member: Pubkey) -> Result<()> {
+        self.require_roster_unlocked()?;
+        // checking if the team still has room for the member
+        require!(
+            self.members.len() < self.max_roster_size as usize,
+            ErrorCode::TeamCapacityFullError
+        );
+        // checking if the member is already in the team
+        require!(
+            !self.members.contains(&member),
+            ErrorCode::MemberAlreadyInTeamError
+        );
+
+        self.members.push(member);
+
+        Ok(())
+    }
+
+    // removes a member that left or was removed with its council seat, role and lineup spot
+    pub fn remove_member(&mut self, member: &Pubkey) {
+        self.members.retain(|x| x != member);
+        self.remove_from_council(member);
+        self.remove_member_role(member);
+        self.lineup.retain(|player| player != member);
+    }
+
+    // sets the active tournament and resets the prize state and the snapshot of the previous tournament
+    pub fn set_active_tournament(
+        &mut self,
+        tournament: Pubkey,
+        prize: u64,
+        prize_mint: Pubkey,
+    ) -> Result<()> {
+        // checking if the released prize of the previous tournament is paid out before its claims are reset
+        require!(
+            !self.result_reported || self.is_prize_settled(),
+            ErrorCode::UnclaimedPrizeError
+        );
+
+        self.active_tournament = tournament;
+        self.prize = prize;
+        self.prize_mint = prize_mint;
+        self.claimed_rewards = vec![];
+        self.result_reported = false;
+        self.entry_snapshot = EntrySnapshot::default();
+        self.prize_settled = false;
+        self.roster_locked = false;
+        // a split accepted for the previous tournament is not accepted for this one
+        self.reset_distribution_vote();
+        self.clear_lineup();
+
+        Ok(())
+    }
+
+    // removes the active tournament and the acceptance of its distribution
+    pub fn clear_active_tournament(&mut self) {
+        self.active_tournament = Pubkey::default();
+        self.reset_distribution_vote();
+        self.roster_locked = false;
+        self.clear_lineup();
+    }
+
+    // the shares need a new distribution proposal to be accepted again
+    pub fn reset_distribution_vote(&mut self) {
+        self.distribution_voting_result = false;
+    }
+
+    pub fn clear_lineup(&mut self) {
+        self.lineup = vec![];
+        self.lineup_tournament = Pubkey::default();
+    }
+
+    // replaces a player of the lineup with a member on the bench or with an outside player
+    // an outside player also takes the place of the outgoing player in the roster
+    pub fn substitute_player(&mut self, player_out: Pubkey, player_in: Pubkey) -> Result<()> {
+        // checking if the incoming player is not in the lineup already
+        require!(
+            !self.lineup.contains(&player_in),
+            ErrorCode::InvalidLineupError
+        );
+
+        // checking if the outgoing player is in the lineup
+        let index = self
+            .lineup
+            .iter()
+            .position(|player| *player == player_out)
+            .ok_or(ErrorCode::InvalidLineupError)?;
+
+        if !self.members.contains(&player_in) {
+            // checking if the outgoing player is not the captain, the captain cannot leave the team
+            require!(
+                self.captain != player_out,
+                ErrorCode::CaptainCannotLeaveTeamError
+            );
+
+            // the outside player takes the roster spot of the outgoing player
+            let member_index = self
+                .members
+                .iter()
+                .position(|member| *member == player_out)
+                .ok_or(ErrorCode::MemberNotInTeamError)?;
+            self.members[member_index] = player_in;
+            self.remove_from_council(&player_out);
+            self.remove_member_role(&player_out);
+        }
+        self.lineup[index] = player_in;
+
+        Ok(())
+    }
+
+    // the prize is settled once it is reported and fully claimed
+    pub fn is_prize_settled(&self) -> bool {
+        self.result_reported && unclaimed_reward(self) == 0
+    }
+
+    // the roster cannot change from the registration to the tournament until its prize is settled
+    pub fn require_roster_unlocked(&self) -> Result<()> {
+        require!(
+            !self.roster_locked || self.is_prize_settled(),
+            ErrorCode::RosterLockedError
+        );
+        Ok(())
+    }
+}
+
+// returns the lamports of the vault that can be paid out as prize
+// the rent exempt minimum always stays in the vault so it is never garbage collected
+pub fn vault_available_lamports(vault: &AccountInfo) -> Result<u64> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    Ok(vault.lamports().saturating_sub(rent_exempt_minimum))
+}
+
+// reward claimed by a member for the active tournament
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ClaimRecord {
+    pub member: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
 pub enum VoteType {
     Yes,
     No,
+    Abstain,
+}
+
+// quorum and approval thresholds of the team votes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct GovernanceConfig {
+    // percentage of the team members that must vote for a result to be valid
+    pub quorum_percentage: u8,
+    // percentage of the yes and no votes that must be yes for a vote to pass
+    pub approval_percentage: u8,
+}
+
+#[derive(PartialEq, Eq)]
+pub enum VoteOutcome {
+    Pending,
+    Passed,
+    Failed,
+}
+
+impl GovernanceConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.quorum_percentage > 0
+                && self.quorum_percentage <= 100
+                && self.approval_percentage > 0
+                && self.approval_percentage <= 100,
+            ErrorCode::InvalidGovernanceConfigError
+        );
+        Ok(())
+    }
+
+    // outcome of an open vote, it is decided as soon as the remaining votes cannot change it
+    pub fn outcome(&self, yes: u8, no: u8, abstain: u8, team_size: usize) -> VoteOutcome {
+        let (yes, no, abstain) = (yes as u64, no as u64, abstain as u64);
+        let remaining = (team_size as u64).saturating_sub(yes + no + abstain);
+        let approval = self.approval_percentage as u64;
+
+        // passed if the quorum is reached and the remaining members cannot outvote the yes votes
+        if self.has_quorum(yes + no + abstain, team_size)
+            && yes > 0
+            && yes * 100 >= approval * (yes + no + remaining)
+        {
+            return VoteOutcome::Passed;
+        }
+        // failed if the vote cannot pass even when all remaining members vote yes
+        if yes + remaining == 0 || (yes + remaining) * 100 < approval * (yes + no + remaining) {
+            return VoteOutcome::Failed;
+        }
+
+        VoteOutcome::Pending
+    }
+
+    // result of a vote at its deadline with the votes cast in time
+    pub fn final_outcome(&self, yes: u8, no: u8, abstain: u8, team_size: usize) -> bool {
+        let (yes, no, abstain) = (yes as u64, no as u64, abstain as u64);
+
+        self.has_quorum(yes + no + abstain, team_size)
+            && yes > 0
+            && yes * 100 >= self.approval_percentage as u64 * (yes + no)
+    }
+
+    fn has_quorum(&self, votes: u64, team_size: usize) -> bool {
+        votes * 100 >= self.quorum_percentage as u64 * team_size as u64
+    }
 }
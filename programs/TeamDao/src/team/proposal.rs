@@ -0,0 +1,193 @@
+use super::errors::ErrorCode;
+use super::*;
+
+// Proposal account struct, a generic team vote
+// the payload is the borsh serialized argument of the proposal kind
+#[account]
+pub struct ProposalAccount {
+    pub team: Pubkey,
+    pub proposer: Pubkey,
+    pub id: u64,
+    pub bump: u8,
+    pub kind: ProposalKind,
+    pub payload: Vec<u8>,
+    pub yes_votes: u8,
+    pub no_votes: u8,
+    pub abstain_votes: u8,
+    pub voters: Vec<Pubkey>,
+    pub deadline: i64,
+    pub status: ProposalStatus,
+}
+
+impl ProposalAccount {
+    // proposals can be open for votes for 30 days at most
+    pub const MAX_VOTING_PERIOD: i64 = 30 * 24 * 60 * 60;
+    // a passed proposal can be executed until 7 days after its deadline
+    pub const EXECUTION_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+    // the payload is stored as it is and the voters grow with the roster size of the team
+    // so the space is computed per proposal
+    pub fn space(payload_len: usize, max_roster_size: u8) -> usize {
+        8 // discriminator
+        + 32 // team pubkey
+        + 32 // proposer pubkey
+        + 8 // id
+        + 1 // bump
+        + 1 // kind
+        + 4 + payload_len // payload vector
+        + 1 // yes_votes
+        + 1 // no_votes
+        + 1 // abstain_votes
+        + 4 + max_roster_size as usize * 32 // voters vector
+        + 8 // deadline
+        + 1 // status
+    }
+
+    // decides the proposal as soon as the result cannot change anymore
+    // with the quorum and approval thresholds of the team
+    pub fn update_status(&mut self, governance: &GovernanceConfig, team_size: usize) {
+        match governance.outcome(self.yes_votes, self.no_votes, self.abstain_votes, team_size) {
+            VoteOutcome::Passed => self.status = ProposalStatus::Passed,
+            VoteOutcome::Failed => self.status = ProposalStatus::Rejected,
+            VoteOutcome::Pending => {}
+        }
+    }
+
+    // checks if the proposal is passed, not executed yet and still in its execution period
+    pub fn require_executable(&self, now: i64) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Passed,
+            ErrorCode::ProposalNotPassedError
+        );
+        require!(
+            now <= self.deadline.saturating_add(Self::EXECUTION_PERIOD),
+            ErrorCode::ProposalExecutionExpiredError
+        );
+        Ok(())
+    }
+
+    // decides the proposal at its deadline with the votes cast in time
+    pub fn finalize_status(&mut self, governance: &GovernanceConfig, team_size: usize) {
+        if governance.final_outcome(self.yes_votes, self.no_votes, self.abstain_votes, team_size) {
+            self.status = ProposalStatus::Passed;
+        } else {
+            self.status = ProposalStatus::Rejected;
+        }
+    }
+}
+
+// governance actions that can be decided with a proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalKind {
+    // payload: tournament address and prize mint, the default pubkey for sol prizes ((Pubkey, Pubkey))
+    JoinTournament,
+    // payload: empty
+    LeaveTournament,
+    // payload: reward distribution shares in basis points (Vec<DistributionShare>)
+    Distribution,
+    // payload: quorum and approval thresholds (GovernanceConfig)
+    UpdateGovernance,
+    // payload: applicant of the join request (Pubkey)
+    // executed with approve join request by vote
+    ApproveJoinRequest,
+    // payload: co-captains and approval threshold of the council (CouncilConfig)
+    UpdateCouncil,
+    // payload: member and its new role ((Pubkey, Role))
+    SetMemberRole,
+    // payload: role and its new permission bits ((Role, u16))
+    SetRolePermissions,
+    // payload: player of the lineup and member of the bench that replaces it ((Pubkey, Pubkey))
+    // the only roster change allowed while the roster is locked
+    EmergencySubstitution,
+}
+
+impl TeamAccount {
+    // checks if the key can vote for a proposal of the kind
+    // the leave votes are resolved against the entry snapshot like the claims
+    pub fn is_proposal_voter(&self, key: &Pubkey, kind: ProposalKind) -> bool {
+        match kind {
+            ProposalKind::LeaveTournament => self.is_tournament_voter(key),
+            _ => self.has_permission(key, kind.vote_permission()),
+        }
+    }
+
+    // size of the team in the votes for a proposal of the kind
+    pub fn count_proposal_voters(&self, kind: ProposalKind) -> usize {
+        self.members
+            .iter()
+            .filter(|member| self.is_proposal_voter(member, kind))
+            .count()
+    }
+}
+
+impl ProposalKind {
+    // permission the proposer needs, the tournaments and the distributions keep their own permissions
+    pub fn create_permission(&self) -> u16 {
+        match self {
+            ProposalKind::JoinTournament => Permission::INIT_TOURNAMENT,
+            ProposalKind::Distribution => Permission::INIT_DISTRIBUTION,
+            _ => Permission::CREATE_PROPOSAL,
+        }
+    }
+
+    // permission the voters need, the members without it do not count for the quorum either
+    pub fn vote_permission(&self) -> u16 {
+        match self {
+            ProposalKind::JoinTournament | ProposalKind::LeaveTournament => {
+                Permission::VOTE_TOURNAMENT
+            }
+            ProposalKind::Distribution => Permission::VOTE_DISTRIBUTION,
+            _ => Permission::VOTE_PROPOSAL,
+        }
+    }
+
+    // checks if the payload can be decoded and is valid for the proposal kind
+    pub fn validate_payload(&self, payload: &[u8]) -> Result<()> {
+        match self {
+            ProposalKind::JoinTournament => {
+                decode_payload::<(Pubkey, Pubkey)>(payload)?;
+            }
+            ProposalKind::LeaveTournament => {
+                require!(payload.is_empty(), ErrorCode::InvalidProposalPayloadError);
+            }
+            ProposalKind::Distribution => {
+                DistributionShare::validate(&decode_payload::<Vec<DistributionShare>>(payload)?)?;
+            }
+            ProposalKind::UpdateGovernance => {
+                decode_payload::<GovernanceConfig>(payload)?.validate()?;
+            }
+            ProposalKind::ApproveJoinRequest => {
+                decode_payload::<Pubkey>(payload)?;
+            }
+            ProposalKind::UpdateCouncil => {
+                decode_payload::<CouncilConfig>(payload)?.validate()?;
+            }
+            ProposalKind::SetMemberRole => {
+                let (_, role) = decode_payload::<(Pubkey, Role)>(payload)?;
+                role.require_assignable()?;
+            }
+            ProposalKind::SetRolePermissions => {
+                decode_payload::<(Role, u16)>(payload)?;
+            }
+            ProposalKind::EmergencySubstitution => {
+                let (player_out, player_in) = decode_payload::<(Pubkey, Pubkey)>(payload)?;
+                require!(player_out != player_in, ErrorCode::InvalidLineupError);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+    Executed,
+}
+
+// decodes the borsh serialized payload of a proposal
+pub fn decode_payload<T: AnchorDeserialize>(payload: &[u8]) -> Result<T> {
+    T::try_from_slice(payload).map_err(|_| error!(ErrorCode::InvalidProposalPayloadError))
+}
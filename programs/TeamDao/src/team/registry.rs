@@ -0,0 +1,50 @@
+use super::*;
+use anchor_lang::solana_program::hash::hash;
+
+// Registry account struct, a program wide pda that assigns the team ids
+#[account]
+#[derive(Default)]
+pub struct RegistryAccount {
+    pub bump: u8,
+    // number of teams ever registered, the id of the next team
+    pub team_count: u64,
+}
+
+impl RegistryAccount {
+    // discriminator + bump + team count
+    pub const LEN: usize = 8 + 1 + 8;
+}
+
+// Name reservation account struct, keeps the name of a team unique
+#[account]
+#[derive(Default)]
+pub struct NameReservationAccount {
+    pub team: Pubkey,
+    pub name_hash: [u8; 32],
+    // index of the team in the registry, seed of its team index account
+    pub index: u64,
+    pub bump: u8,
+}
+
+impl NameReservationAccount {
+    // discriminator + team + name hash + index + bump
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+
+    // the names are compared case insensitive and without the surrounding whitespaces
+    pub fn hash_name(name: &str) -> [u8; 32] {
+        hash(name.trim().to_lowercase().as_bytes()).to_bytes()
+    }
+}
+
+// Team index account struct, lets the clients enumerate the teams by their registry index
+#[account]
+#[derive(Default)]
+pub struct TeamIndexAccount {
+    pub team: Pubkey,
+    pub bump: u8,
+}
+
+impl TeamIndexAccount {
+    // discriminator + team + bump
+    pub const LEN: usize = 8 + 32 + 1;
+}
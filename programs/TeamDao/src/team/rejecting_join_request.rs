@@ -0,0 +1,42 @@
+use super::*;
+
+pub fn rejecting_join_request(ctx: Context<RejectJoinRequest>) -> Result<()> {
+    let team = &ctx.accounts.team_account;
+
+    // checking if the signer can manage join requests
+    team.require_permission(ctx.accounts.signer.key, Permission::MANAGE_JOIN_REQUESTS)?;
+
+    // the join request is closed without adding the member
+    emit!(JoinRequestClosed {
+        team: team.key(),
+        applicant: ctx.accounts.applicant.key(),
+        approved: false,
+    });
+
+    msg!(
+        "The join request of {} to the team {} is rejected",
+        ctx.accounts.applicant.key(),
+        team.name
+    );
+
+    Ok(())
+}
+
+// reject join request instruction, signed by the captain
+// the rent of the join request goes back to the applicant
+#[derive(Accounts)]
+pub struct RejectJoinRequest<'info> {
+    #[account(seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut, seeds=[b"join_request", team_account.key().as_ref(), applicant.key().as_ref()], bump = join_request_account.bump, has_one = applicant, close = applicant)]
+    pub join_request_account: Account<'info, JoinRequestAccount>,
+
+    #[account(mut)]
+    pub applicant: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
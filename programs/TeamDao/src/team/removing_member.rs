@@ -1,14 +1,15 @@
 use super::errors::ErrorCode;
 use super::*;
 
-pub fn removing_member(
-    ctx: Context<RemoveMember>,
-    _team_name: String,
-    _team_id: u64,
-    member: Pubkey,
-) -> Result<()> {
+pub fn removing_member(ctx: Context<RemoveMember>, member: Pubkey) -> Result<()> {
     let team = &mut ctx.accounts.team_account;
 
+    // checking if the signer can remove members
+    team.require_permission(ctx.accounts.signer.key, Permission::REMOVE_MEMBER)?;
+    // checking if the council approved the removal
+    team.check_council_approval(ctx.accounts.signer.key, ctx.remaining_accounts)?;
+    // checking if the roster is not locked by the tournament
+    team.require_roster_unlocked()?;
     // checking if the team has at least 2 players if not, return error
     require!(team.members.len() > 1, ErrorCode::TeamCapacityLowError);
     // checkinf if the caller is the captain of the team
@@ -26,7 +27,13 @@ pub fn removing_member(
     );
 
     // removing member from team
-    team.members.retain(|&x| x != member);
+    team.remove_member(&member);
+
+    emit!(MemberRemoved {
+        team: team.key(),
+        member,
+        left: false,
+    });
 
     msg!(
         "{} is successfully removed from the team {}",
@@ -38,9 +45,8 @@ pub fn removing_member(
 }
 
 #[derive(Accounts)]
-#[instruction(team_name: String, team_id: u64)]
 pub struct RemoveMember<'info> {
-    #[account(mut, seeds=[team_name.as_bytes(), &team_id.to_ne_bytes()], bump = team_account.bump)]
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
     pub team_account: Account<'info, TeamAccount>,
 
     #[account(mut)]
@@ -0,0 +1,69 @@
+use super::errors::ErrorCode;
+use super::*;
+
+pub fn requesting_to_join(ctx: Context<RequestToJoin>) -> Result<()> {
+    let team = &mut ctx.accounts.team_account;
+    let applicant = *ctx.accounts.signer.key;
+
+    // checking if the applicant is already in the team
+    require!(
+        !team.members.contains(&applicant),
+        ErrorCode::MemberAlreadyInTeamError
+    );
+
+    // open teams accept the request while they have room for the member
+    // the council approved the members of the team when it opened it
+    if team.open_to_join && team.members.len() < team.max_roster_size as usize {
+        team.add_member(applicant)?;
+
+        emit!(MemberAdded {
+            team: team.key(),
+            member: applicant,
+        });
+
+        msg!(
+            "{} is successfully added to the team {}",
+            applicant,
+            team.name
+        );
+
+        // the request is not needed anymore, its rent goes back to the applicant
+        return ctx
+            .accounts
+            .join_request_account
+            .close(ctx.accounts.signer.to_account_info());
+    }
+
+    let request = &mut ctx.accounts.join_request_account;
+
+    request.bump = *ctx
+        .bumps
+        .get("join_request_account")
+        .ok_or(ErrorCode::InvalidBumpSeeds)?;
+    request.team = team.key();
+    request.applicant = applicant;
+
+    emit!(JoinRequested {
+        team: team.key(),
+        applicant,
+    });
+
+    msg!("{} requested to join the team {}", applicant, team.name);
+
+    Ok(())
+}
+
+// request to join instruction, signed by the applicant
+#[derive(Accounts)]
+pub struct RequestToJoin<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(init, payer = signer, space = JoinRequestAccount::LEN, seeds=[b"join_request", team_account.key().as_ref(), signer.key().as_ref()], bump)]
+    pub join_request_account: Account<'info, JoinRequestAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+pub fn revoking_invite(ctx: Context<RevokeInvite>) -> Result<()> {
+    let team = &ctx.accounts.team_account;
+
+    // checking if the signer can invite members
+    team.require_permission(ctx.accounts.signer.key, Permission::INVITE_MEMBER)?;
+
+    // the invitation is closed without adding the member
+    emit!(InvitationClosed {
+        team: team.key(),
+        invitee: ctx.accounts.invitation_account.invitee,
+        accepted: false,
+    });
+
+    msg!(
+        "The invitation of {} to the team {} is revoked",
+        ctx.accounts.invitation_account.invitee,
+        team.name
+    );
+
+    Ok(())
+}
+
+// revoke invite instruction, signed by the captain
+// the rent of the invitation goes back to the inviter
+#[derive(Accounts)]
+pub struct RevokeInvite<'info> {
+    #[account(seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut, seeds=[b"invite", team_account.key().as_ref(), invitation_account.invitee.as_ref()], bump = invitation_account.bump, has_one = inviter, close = inviter)]
+    pub invitation_account: Account<'info, InvitationAccount>,
+
+    #[account(mut)]
+    pub inviter: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
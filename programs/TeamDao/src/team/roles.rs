@@ -0,0 +1,184 @@
+use super::errors::ErrorCode;
+use super::*;
+
+// role of a team member, the captain and the co-captains follow the captain and the council fields
+// the members without an assigned role are players
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Captain,
+    CoCaptain,
+    Player,
+    Substitute,
+    Coach,
+    Manager,
+}
+
+impl Role {
+    pub const COUNT: usize = 6;
+
+    // checks if the role can be assigned to a member, the captain and the co-captains are elected
+    pub fn require_assignable(&self) -> Result<()> {
+        require!(
+            !matches!(self, Role::Captain | Role::CoCaptain),
+            ErrorCode::InvalidRoleError
+        );
+        Ok(())
+    }
+
+    pub fn default_permissions(&self) -> u16 {
+        let voter = Permission::VOTE_TOURNAMENT
+            | Permission::VOTE_DISTRIBUTION
+            | Permission::CREATE_PROPOSAL
+            | Permission::VOTE_PROPOSAL;
+
+        match self {
+            Role::Captain => Permission::ALL,
+            Role::CoCaptain => {
+                voter
+                    | Permission::INVITE_MEMBER
+                    | Permission::REMOVE_MEMBER
+                    | Permission::INIT_TOURNAMENT
+                    | Permission::INIT_DISTRIBUTION
+                    | Permission::MANAGE_JOIN_REQUESTS
+                    | Permission::SET_LINEUP
+            }
+            Role::Player | Role::Substitute => voter,
+            Role::Coach => {
+                Permission::VOTE_TOURNAMENT
+                    | Permission::CREATE_PROPOSAL
+                    | Permission::VOTE_PROPOSAL
+                    | Permission::SET_LINEUP
+            }
+            Role::Manager => {
+                voter
+                    | Permission::INVITE_MEMBER
+                    | Permission::INIT_TOURNAMENT
+                    | Permission::INIT_DISTRIBUTION
+                    | Permission::MANAGE_JOIN_REQUESTS
+                    | Permission::UPDATE_METADATA
+                    | Permission::SET_LINEUP
+            }
+        }
+    }
+
+    // permissions of every role indexed by the role
+    pub fn default_role_permissions() -> [u16; Role::COUNT] {
+        [
+            Role::Captain.default_permissions(),
+            Role::CoCaptain.default_permissions(),
+            Role::Player.default_permissions(),
+            Role::Substitute.default_permissions(),
+            Role::Coach.default_permissions(),
+            Role::Manager.default_permissions(),
+        ]
+    }
+}
+
+// permission bits checked by the team instructions
+pub struct Permission;
+
+impl Permission {
+    pub const INVITE_MEMBER: u16 = 1 << 0;
+    pub const REMOVE_MEMBER: u16 = 1 << 1;
+    pub const INIT_TOURNAMENT: u16 = 1 << 2;
+    pub const INIT_DISTRIBUTION: u16 = 1 << 3;
+    pub const VOTE_TOURNAMENT: u16 = 1 << 4;
+    pub const VOTE_DISTRIBUTION: u16 = 1 << 5;
+    pub const CREATE_PROPOSAL: u16 = 1 << 6;
+    pub const VOTE_PROPOSAL: u16 = 1 << 7;
+    pub const MANAGE_JOIN_REQUESTS: u16 = 1 << 8;
+    pub const UPDATE_METADATA: u16 = 1 << 9;
+    pub const SET_LINEUP: u16 = 1 << 10;
+    pub const ALL: u16 = (1 << 11) - 1;
+}
+
+// role assigned to a member, the members without an assignment are players
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct MemberRole {
+    pub member: Pubkey,
+    pub role: Role,
+}
+
+impl TeamAccount {
+    // role of the member, none if the key is not a member of the team
+    pub fn role_of(&self, key: &Pubkey) -> Option<Role> {
+        if !self.members.contains(key) {
+            return None;
+        }
+        if self.captain == *key {
+            return Some(Role::Captain);
+        }
+        if self.council.contains(key) {
+            return Some(Role::CoCaptain);
+        }
+
+        Some(
+            self.member_roles
+                .iter()
+                .find(|member_role| member_role.member == *key)
+                .map_or(Role::Player, |member_role| member_role.role),
+        )
+    }
+
+    // the captain has every permission so the team cannot be locked
+    pub fn has_permission(&self, key: &Pubkey, permission: u16) -> bool {
+        match self.role_of(key) {
+            Some(Role::Captain) => true,
+            Some(role) => self.role_permissions[role as usize] & permission == permission,
+            None => false,
+        }
+    }
+
+    pub fn require_permission(&self, key: &Pubkey, permission: u16) -> Result<()> {
+        require!(
+            self.has_permission(key, permission),
+            ErrorCode::PermissionDeniedError
+        );
+        Ok(())
+    }
+
+    // number of members with the permission, the size of the team in the votes that need it
+    pub fn count_with_permission(&self, permission: u16) -> usize {
+        self.members
+            .iter()
+            .filter(|member| self.has_permission(member, permission))
+            .count()
+    }
+
+    pub fn set_member_role(&mut self, member: Pubkey, role: Role) -> Result<()> {
+        role.require_assignable()?;
+        // checking if the member is in the team
+        require!(
+            self.members.contains(&member),
+            ErrorCode::MemberNotInTeamError
+        );
+
+        self.member_roles
+            .retain(|member_role| member_role.member != member);
+        if role != Role::Player {
+            self.member_roles.push(MemberRole { member, role });
+        }
+
+        Ok(())
+    }
+
+    pub fn set_role_permissions(&mut self, role: Role, permissions: u16) -> Result<()> {
+        // checking if the role is not the captain, the captain keeps every permission
+        require!(role != Role::Captain, ErrorCode::InvalidRoleError);
+        // checking if the permissions are known bits
+        require!(
+            permissions & !Permission::ALL == 0,
+            ErrorCode::InvalidPermissionsError
+        );
+
+        self.role_permissions[role as usize] = permissions;
+
+        Ok(())
+    }
+
+    // drops the role of a member that left the team
+    pub fn remove_member_role(&mut self, member: &Pubkey) {
+        self.member_roles
+            .retain(|member_role| member_role.member != *member);
+    }
+}
@@ -0,0 +1,71 @@
+use super::errors::ErrorCode;
+use super::*;
+use crate::tournament::TournamentAccount;
+
+pub fn setting_lineup(ctx: Context<SetLineup>, lineup: Vec<Pubkey>) -> Result<()> {
+    let team = &mut ctx.accounts.team_account;
+    let tournament = &ctx.accounts.tournament_account;
+
+    // checking if the signer can set the lineup
+    team.require_permission(ctx.accounts.signer.key, Permission::SET_LINEUP)?;
+
+    // checking if the tournament account is the active tournament of the team
+    require!(
+        team.active_tournament != Pubkey::default(),
+        ErrorCode::NoActiveTournamentError
+    );
+    require!(
+        team.active_tournament == tournament.key(),
+        ErrorCode::InvalidTournamentError
+    );
+
+    // checking if the team is not registered with its lineup yet
+    // after the registration players are replaced with an emergency substitution
+    team.require_roster_unlocked()?;
+
+    // checking if the lineup has as many players as the tournament requires
+    require!(
+        lineup.len() == tournament.roster_size as usize,
+        ErrorCode::InvalidLineupError
+    );
+    // checking if every player of the lineup is a member of the team, only once
+    for (i, player) in lineup.iter().enumerate() {
+        require!(
+            team.members.contains(player),
+            ErrorCode::MemberNotInTeamError
+        );
+        require!(!lineup[..i].contains(player), ErrorCode::InvalidLineupError);
+    }
+
+    team.lineup = lineup;
+    team.lineup_tournament = tournament.key();
+
+    emit!(LineupSet {
+        team: team.key(),
+        tournament: tournament.key(),
+        lineup: team.lineup.clone(),
+    });
+
+    msg!(
+        "The lineup of {} is set for the tournament {}",
+        team.name,
+        tournament.name
+    );
+
+    Ok(())
+}
+
+// set lineup instruction
+#[derive(Accounts)]
+pub struct SetLineup<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(seeds=[b"tournament", tournament_account.organizer.as_ref(), tournament_account.name.as_bytes()], bump = tournament_account.bump)]
+    pub tournament_account: Account<'info, TournamentAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,40 @@
+use super::errors::ErrorCode;
+use super::*;
+
+pub fn setting_member_role(ctx: Context<SetMemberRole>, member: Pubkey, role: Role) -> Result<()> {
+    let team = &mut ctx.accounts.team_account;
+
+    // checking if the signer is the captain
+    require!(
+        team.captain == *ctx.accounts.signer.key,
+        ErrorCode::NotCaptainError
+    );
+
+    team.set_member_role(member, role)?;
+
+    emit!(MemberRoleChanged {
+        team: team.key(),
+        member,
+        role,
+    });
+
+    msg!(
+        "The role of {} is changed in the team {}",
+        member,
+        team.name
+    );
+
+    Ok(())
+}
+
+// set member role instruction
+#[derive(Accounts)]
+pub struct SetMemberRole<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
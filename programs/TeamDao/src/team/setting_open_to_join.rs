@@ -0,0 +1,34 @@
+use super::*;
+
+pub fn setting_open_to_join(ctx: Context<SetOpenToJoin>, open_to_join: bool) -> Result<()> {
+    let team = &mut ctx.accounts.team_account;
+
+    // checking if the signer can manage join requests
+    team.require_permission(ctx.accounts.signer.key, Permission::MANAGE_JOIN_REQUESTS)?;
+    // checking if the council approved the setting, an open team adds members without invitations
+    team.check_council_approval(ctx.accounts.signer.key, ctx.remaining_accounts)?;
+
+    // join requests of an open team are accepted while it has room
+    team.open_to_join = open_to_join;
+
+    emit!(OpenToJoinChanged {
+        team: team.key(),
+        open_to_join,
+    });
+
+    msg!("{} is open to join: {}", team.name, team.open_to_join);
+
+    Ok(())
+}
+
+// set open to join instruction
+#[derive(Accounts)]
+pub struct SetOpenToJoin<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,43 @@
+use super::errors::ErrorCode;
+use super::*;
+
+pub fn setting_role_permissions(
+    ctx: Context<SetRolePermissions>,
+    role: Role,
+    permissions: u16,
+) -> Result<()> {
+    let team = &mut ctx.accounts.team_account;
+
+    // checking if the signer is the captain
+    require!(
+        team.captain == *ctx.accounts.signer.key,
+        ErrorCode::NotCaptainError
+    );
+
+    team.set_role_permissions(role, permissions)?;
+
+    emit!(RolePermissionsChanged {
+        team: team.key(),
+        role,
+        permissions,
+    });
+
+    msg!(
+        "The permissions of a role are changed in the team {}",
+        team.name
+    );
+
+    Ok(())
+}
+
+// set role permissions instruction
+#[derive(Accounts)]
+pub struct SetRolePermissions<'info> {
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
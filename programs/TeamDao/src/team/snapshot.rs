@@ -0,0 +1,63 @@
+use super::*;
+
+// roster and accepted distribution of the team when it registered to a tournament
+// the claims and the leave votes of the tournament are resolved against it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct EntrySnapshot {
+    pub tournament: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub shares: Vec<DistributionShare>,
+}
+
+impl TeamAccount {
+    // records the roster and the distribution at the registration to the active tournament
+    pub fn take_entry_snapshot(&mut self) {
+        self.entry_snapshot = EntrySnapshot {
+            tournament: self.active_tournament,
+            members: self.members.clone(),
+            shares: self.distribution_shares.clone(),
+        };
+    }
+
+    pub fn has_entry_snapshot(&self) -> bool {
+        self.entry_snapshot.tournament != Pubkey::default()
+    }
+
+    // checks if the snapshot belongs to the active tournament, the team is registered to it
+    pub fn is_registered_to_active_tournament(&self) -> bool {
+        self.has_entry_snapshot() && self.entry_snapshot.tournament == self.active_tournament
+    }
+
+    // members that earned the prize, the live roster until the team is registered
+    pub fn prize_members(&self) -> &[Pubkey] {
+        if self.has_entry_snapshot() {
+            &self.entry_snapshot.members
+        } else {
+            &self.members
+        }
+    }
+
+    // accepted distribution of the prize, the live distribution until the team is registered
+    pub fn prize_shares(&self) -> &[DistributionShare] {
+        if self.has_entry_snapshot() {
+            &self.entry_snapshot.shares
+        } else {
+            &self.distribution_shares
+        }
+    }
+
+    // checks if the key can vote about the active tournament
+    // once the team is registered only the members of the snapshot can vote
+    pub fn is_tournament_voter(&self, key: &Pubkey) -> bool {
+        (!self.is_registered_to_active_tournament() || self.entry_snapshot.members.contains(key))
+            && self.has_permission(key, Permission::VOTE_TOURNAMENT)
+    }
+
+    // size of the team in the votes about the active tournament
+    pub fn count_tournament_voters(&self) -> usize {
+        self.members
+            .iter()
+            .filter(|member| self.is_tournament_voter(member))
+            .count()
+    }
+}
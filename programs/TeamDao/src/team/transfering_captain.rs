@@ -1,12 +1,7 @@
 use super::errors::ErrorCode;
 use super::*;
 
-pub fn transfering_captain(
-    ctx: Context<TransferCaptain>,
-    _team_name: String,
-    _team_id: u64,
-    member: Pubkey,
-) -> Result<()> {
+pub fn transfering_captain(ctx: Context<TransferCaptain>, member: Pubkey) -> Result<()> {
     let team = &mut ctx.accounts.team_account;
 
     // checking if the signer is captain
@@ -14,6 +9,8 @@ pub fn transfering_captain(
         team.captain == *ctx.accounts.signer.key,
         ErrorCode::NotCaptainError
     );
+    // checking if the council approved the transfer
+    team.check_council_approval(ctx.accounts.signer.key, ctx.remaining_accounts)?;
     // checking if the member is in the team
     require!(
         team.members.contains(&member),
@@ -21,7 +18,17 @@ pub fn transfering_captain(
     );
 
     // transferring captain role
+    let old_captain = team.captain;
     team.captain = member;
+    // the new captain is no longer a co-captain
+    team.remove_from_council(&member);
+    team.remove_member_role(&member);
+
+    emit!(CaptainTransferred {
+        team: team.key(),
+        old_captain,
+        new_captain: member,
+    });
 
     msg!(
         "Captain role is successfully transferred to {} in the team {}",
@@ -33,9 +40,8 @@ pub fn transfering_captain(
 }
 
 #[derive(Accounts)]
-#[instruction(team_name: String, team_id: u64)]
 pub struct TransferCaptain<'info> {
-    #[account(mut, seeds=[team_name.as_bytes(), &team_id.to_ne_bytes()], bump = team_account.bump)]
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
     pub team_account: Account<'info, TeamAccount>,
 
     #[account(mut)]
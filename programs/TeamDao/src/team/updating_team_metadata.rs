@@ -0,0 +1,39 @@
+use super::*;
+
+pub fn updating_team_metadata(
+    ctx: Context<UpdateTeamMetadata>,
+    metadata: TeamMetadata,
+) -> Result<()> {
+    let team = &ctx.accounts.team_account;
+
+    // checking if the signer can update the metadata
+    team.require_permission(ctx.accounts.signer.key, Permission::UPDATE_METADATA)?;
+    // checking if the metadata fits into the account
+    metadata.validate()?;
+
+    ctx.accounts.metadata_account.metadata = metadata;
+
+    emit!(TeamMetadataUpdated { team: team.key() });
+
+    msg!(
+        "The metadata of the team {} is successfully updated",
+        team.name
+    );
+
+    Ok(())
+}
+
+// update team metadata instruction
+#[derive(Accounts)]
+pub struct UpdateTeamMetadata<'info> {
+    #[account(seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(mut, seeds=[b"metadata", team_account.key().as_ref()], bump = metadata_account.bump)]
+    pub metadata_account: Account<'info, TeamMetadataAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,41 @@
+use super::*;
+use crate::team::errors::ErrorCode;
+
+pub fn closing_registration(ctx: Context<CloseRegistration>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament_account;
+
+    // checking if the signer is the organizer
+    require!(
+        tournament.organizer == *ctx.accounts.organizer.key,
+        ErrorCode::NotOrganizerError
+    );
+    // checking if the registration is open
+    require!(
+        tournament.status == TournamentStatus::RegistrationOpen,
+        ErrorCode::InvalidTournamentStatusError
+    );
+
+    tournament.status = TournamentStatus::RegistrationClosed;
+
+    emit!(RegistrationClosed {
+        tournament: tournament.key(),
+        registered_teams: tournament.registered_teams.len() as u8,
+    });
+
+    msg!(
+        "Registration of the tournament {} is closed",
+        tournament.name
+    );
+
+    Ok(())
+}
+
+// close registration instruction
+#[derive(Accounts)]
+pub struct CloseRegistration<'info> {
+    #[account(mut, seeds=[b"tournament", tournament_account.organizer.as_ref(), tournament_account.name.as_bytes()], bump = tournament_account.bump)]
+    pub tournament_account: Account<'info, TournamentAccount>,
+
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+}
@@ -0,0 +1,41 @@
+use super::*;
+use crate::team::errors::ErrorCode;
+
+pub fn closing_tournament(ctx: Context<CloseTournament>) -> Result<()> {
+    let tournament = &ctx.accounts.tournament_account;
+
+    // checking if the signer is the organizer
+    require!(
+        tournament.organizer == *ctx.accounts.organizer.key,
+        ErrorCode::NotOrganizerError
+    );
+    // checking if the tournament is finalized
+    require!(
+        tournament.status == TournamentStatus::Finalized,
+        ErrorCode::InvalidTournamentStatusError
+    );
+    // checking if the result of every registered team is reported
+    require!(
+        tournament.results.len() == tournament.registered_teams.len(),
+        ErrorCode::ResultsPendingError
+    );
+
+    emit!(TournamentClosed {
+        tournament: tournament.key(),
+        refund: tournament.to_account_info().lamports(),
+    });
+
+    msg!("The tournament {} is closed", tournament.name);
+
+    Ok(())
+}
+
+// close tournament instruction, the unused prize pool and the entry fees go back to the organizer
+#[derive(Accounts)]
+pub struct CloseTournament<'info> {
+    #[account(mut, close = organizer, seeds=[b"tournament", tournament_account.organizer.as_ref(), tournament_account.name.as_bytes()], bump = tournament_account.bump)]
+    pub tournament_account: Account<'info, TournamentAccount>,
+
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+}
@@ -0,0 +1,82 @@
+use super::*;
+use crate::team::errors::ErrorCode;
+use crate::team::TeamAccount;
+use anchor_lang::system_program;
+
+pub fn creating_tournament(
+    ctx: Context<CreateTournament>,
+    tournament_name: String,
+    entry_fee: u64,
+    prize_pool: u64,
+    start_slot: u64,
+    end_slot: u64,
+    max_teams: u8,
+    oracle: Pubkey,
+    roster_size: u8,
+) -> Result<()> {
+    // checking if the name fits into the account
+    require!(
+        tournament_name.len() <= TournamentAccount::MAX_NAME_LEN,
+        ErrorCode::InvalidTournamentNameError
+    );
+    // checking if the schedule and the capacity of the tournament are valid
+    require!(
+        start_slot < end_slot && max_teams > 0 && max_teams <= TournamentAccount::MAX_TEAMS,
+        ErrorCode::InvalidTournamentScheduleError
+    );
+    // checking if the roster size is valid
+    require!(
+        roster_size > 0 && roster_size <= TeamAccount::MAX_ROSTER_SIZE,
+        ErrorCode::InvalidRosterSizeError
+    );
+
+    // depositing the prize pool to the tournament account
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.organizer.to_account_info(),
+                to: ctx.accounts.tournament_account.to_account_info(),
+            },
+        ),
+        prize_pool,
+    )?;
+
+    let tournament = &mut ctx.accounts.tournament_account;
+
+    tournament.bump = *ctx
+        .bumps
+        .get("tournament_account")
+        .ok_or(ErrorCode::InvalidBumpSeeds)?;
+
+    // assigning required parameters to the tournament
+    tournament.organizer = *ctx.accounts.organizer.key;
+    tournament.name = tournament_name;
+    tournament.entry_fee = entry_fee;
+    tournament.prize_pool = prize_pool;
+    tournament.start_slot = start_slot;
+    tournament.end_slot = end_slot;
+    tournament.max_teams = max_teams;
+    tournament.status = TournamentStatus::Created;
+    tournament.oracle = oracle;
+    tournament.roster_size = roster_size;
+
+    msg!("Tournament created");
+    msg!("Tournament name: {}", tournament.name);
+    msg!("Tournament organizer: {}", tournament.organizer);
+
+    Ok(())
+}
+
+// derive macro for create tournament instruction
+#[derive(Accounts)]
+#[instruction(tournament_name: String, _entry_fee: u64, _prize_pool: u64, _start_slot: u64, _end_slot: u64, max_teams: u8)]
+pub struct CreateTournament<'info> {
+    #[account(init, payer = organizer, space = TournamentAccount::space(max_teams), seeds=[b"tournament", organizer.key().as_ref(), tournament_name.as_bytes()], bump)]
+    pub tournament_account: Account<'info, TournamentAccount>,
+
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
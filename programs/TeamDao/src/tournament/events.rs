@@ -0,0 +1,44 @@
+use super::*;
+
+// events emitted by the tournament instructions, used by indexers and bots
+// every event carries the tournament account so it can be filtered per tournament
+
+#[event]
+pub struct RegistrationOpened {
+    pub tournament: Pubkey,
+}
+
+#[event]
+pub struct RegistrationClosed {
+    pub tournament: Pubkey,
+    pub registered_teams: u8,
+}
+
+#[event]
+pub struct TournamentFinalized {
+    pub tournament: Pubkey,
+}
+
+#[event]
+pub struct ResultReported {
+    pub tournament: Pubkey,
+    pub team: Pubkey,
+    pub placement: u8,
+    pub prize: u64,
+}
+
+#[event]
+pub struct PrizeReleased {
+    pub tournament: Pubkey,
+    pub team: Pubkey,
+    // default pubkey for sol prizes
+    pub prize_mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TournamentClosed {
+    pub tournament: Pubkey,
+    // lamports refunded to the organizer
+    pub refund: u64,
+}
@@ -0,0 +1,42 @@
+use super::*;
+use crate::team::errors::ErrorCode;
+
+pub fn finalizing_tournament(ctx: Context<FinalizeTournament>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament_account;
+
+    // checking if the signer is the organizer
+    require!(
+        tournament.organizer == *ctx.accounts.organizer.key,
+        ErrorCode::NotOrganizerError
+    );
+    // checking if the registration is closed
+    require!(
+        tournament.status == TournamentStatus::RegistrationClosed,
+        ErrorCode::InvalidTournamentStatusError
+    );
+    // checking if the tournament has ended
+    require!(
+        Clock::get()?.slot >= tournament.end_slot,
+        ErrorCode::TournamentNotEndedError
+    );
+
+    tournament.status = TournamentStatus::Finalized;
+
+    emit!(TournamentFinalized {
+        tournament: tournament.key(),
+    });
+
+    msg!("The tournament {} is finalized", tournament.name);
+
+    Ok(())
+}
+
+// finalize tournament instruction
+#[derive(Accounts)]
+pub struct FinalizeTournament<'info> {
+    #[account(mut, seeds=[b"tournament", tournament_account.organizer.as_ref(), tournament_account.name.as_bytes()], bump = tournament_account.bump)]
+    pub tournament_account: Account<'info, TournamentAccount>,
+
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+}
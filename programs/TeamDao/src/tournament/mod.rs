@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+pub mod closing_registration;
+pub mod closing_tournament;
+pub mod creating_tournament;
+pub mod events;
+pub mod finalizing_tournament;
+pub mod opening_registration;
+pub mod reporting_result;
+
+pub use closing_registration::*;
+pub use closing_tournament::*;
+pub use creating_tournament::*;
+pub use events::*;
+pub use finalizing_tournament::*;
+pub use opening_registration::*;
+pub use reporting_result::*;
+
+// Tournament account struct
+#[account]
+pub struct TournamentAccount {
+    pub organizer: Pubkey,
+    pub bump: u8,
+    pub name: String,
+    pub entry_fee: u64,
+    pub prize_pool: u64,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub max_teams: u8,
+    pub registered_teams: Vec<Pubkey>,
+    pub status: TournamentStatus,
+    pub oracle: Pubkey,
+    pub results: Vec<TournamentResult>,
+    pub roster_size: u8,
+}
+
+impl TournamentAccount {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_TEAMS: u8 = 128;
+
+    // the registered teams vector grows with max_teams so the space is computed per tournament
+    pub fn space(max_teams: u8) -> usize {
+        8 // discriminator
+        + 32 // organizer pubkey
+        + 1 // bump
+        + 4 + Self::MAX_NAME_LEN // name
+        + 8 // entry_fee
+        + 8 // prize_pool
+        + 8 // start_slot
+        + 8 // end_slot
+        + 1 // max_teams
+        + 4 + max_teams as usize * 32 // registered_teams vector
+        + 1 // status
+        + 32 // oracle pubkey
+        + 4 + max_teams as usize * 41 // results vector
+        + 1 // roster_size
+    } // 9496 bytes for 128 teams < 10k
+}
+
+// placement and prize of a team reported by the organizer or the oracle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TournamentResult {
+    pub team: Pubkey,
+    pub placement: u8,
+    pub prize: u64,
+}
+
+// lifecycle of a tournament, every step is triggered by the organizer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TournamentStatus {
+    Created,
+    RegistrationOpen,
+    RegistrationClosed,
+    Finalized,
+}
@@ -0,0 +1,40 @@
+use super::*;
+use crate::team::errors::ErrorCode;
+
+pub fn opening_registration(ctx: Context<OpenRegistration>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament_account;
+
+    // checking if the signer is the organizer
+    require!(
+        tournament.organizer == *ctx.accounts.organizer.key,
+        ErrorCode::NotOrganizerError
+    );
+    // registration can only be opened once, right after the tournament is created
+    require!(
+        tournament.status == TournamentStatus::Created,
+        ErrorCode::InvalidTournamentStatusError
+    );
+
+    tournament.status = TournamentStatus::RegistrationOpen;
+
+    emit!(RegistrationOpened {
+        tournament: tournament.key(),
+    });
+
+    msg!(
+        "Registration of the tournament {} is opened",
+        tournament.name
+    );
+
+    Ok(())
+}
+
+// open registration instruction
+#[derive(Accounts)]
+pub struct OpenRegistration<'info> {
+    #[account(mut, seeds=[b"tournament", tournament_account.organizer.as_ref(), tournament_account.name.as_bytes()], bump = tournament_account.bump)]
+    pub tournament_account: Account<'info, TournamentAccount>,
+
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+}
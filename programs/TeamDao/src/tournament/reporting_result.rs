@@ -0,0 +1,181 @@
+use super::*;
+use crate::team::errors::ErrorCode;
+use crate::team::TeamAccount;
+use anchor_spl::token::{Mint, TokenAccount};
+
+pub fn reporting_result(ctx: Context<ReportResult>, placement: u8, prize: u64) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament_account;
+    let team = &mut ctx.accounts.team_account;
+
+    // tournament prizes are paid in sol
+    require!(
+        team.prize_mint == Pubkey::default(),
+        ErrorCode::InvalidPrizeMintError
+    );
+    // checking if the prize pool can cover the prize
+    require!(
+        prize <= tournament.prize_pool,
+        ErrorCode::InsufficientPrizePoolError
+    );
+
+    record_result(tournament, team, ctx.accounts.signer.key, placement, prize)?;
+    tournament.prize_pool -= prize;
+
+    // releasing the prize from the tournament account into the team vault
+    let from = ctx.accounts.tournament_account.to_account_info();
+    let to = ctx.accounts.vault.to_account_info();
+
+    **from.try_borrow_mut_lamports()? -= prize;
+    **to.try_borrow_mut_lamports()? += prize;
+
+    emit!(PrizeReleased {
+        tournament: ctx.accounts.tournament_account.key(),
+        team: ctx.accounts.team_account.key(),
+        prize_mint: Pubkey::default(),
+        amount: prize,
+    });
+
+    msg!(
+        "{} finished the tournament {} at place {}",
+        ctx.accounts.team_account.name,
+        ctx.accounts.tournament_account.name,
+        placement
+    );
+
+    Ok(())
+}
+
+pub fn reporting_token_result(
+    ctx: Context<ReportTokenResult>,
+    placement: u8,
+    prize: u64,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament_account;
+    let team = &mut ctx.accounts.team_account;
+
+    // checking if the prize of the team is paid in spl tokens
+    require!(
+        team.prize_mint != Pubkey::default(),
+        ErrorCode::InvalidPrizeMintError
+    );
+    // checking if the prize is deposited to the team token vault by the organizer
+    require!(
+        ctx.accounts.prize_vault.amount >= prize,
+        ErrorCode::VaultNotFundedError
+    );
+
+    record_result(tournament, team, ctx.accounts.signer.key, placement, prize)?;
+
+    // the deposited tokens are released to the members of the team
+    emit!(PrizeReleased {
+        tournament: tournament.key(),
+        team: team.key(),
+        prize_mint: team.prize_mint,
+        amount: prize,
+    });
+
+    msg!(
+        "{} finished the tournament {} at place {}",
+        team.name,
+        tournament.name,
+        placement
+    );
+
+    Ok(())
+}
+
+// records the result of the team, the prize becomes claimable by the members
+fn record_result(
+    tournament: &mut Account<TournamentAccount>,
+    team: &mut Account<TeamAccount>,
+    signer: &Pubkey,
+    placement: u8,
+    prize: u64,
+) -> Result<()> {
+    let team_key = team.key();
+
+    // checking if the signer is the organizer or the oracle
+    require!(
+        tournament.organizer == *signer || tournament.oracle == *signer,
+        ErrorCode::NotResultReporterError
+    );
+    // results can only be reported after the tournament is finalized
+    require!(
+        tournament.status == TournamentStatus::Finalized,
+        ErrorCode::InvalidTournamentStatusError
+    );
+    // checking if the team is registered to the tournament
+    require!(
+        tournament.registered_teams.contains(&team_key),
+        ErrorCode::TeamNotRegisteredError
+    );
+    // checking if the result of the team is not reported yet
+    require!(
+        !tournament.results.iter().any(|r| r.team == team_key),
+        ErrorCode::ResultAlreadyReportedError
+    );
+
+    // checking if the tournament is the active tournament of the team
+    require!(
+        team.active_tournament == tournament.key(),
+        ErrorCode::InvalidTournamentError
+    );
+
+    // recording the result
+    tournament.results.push(TournamentResult {
+        team: team_key,
+        placement,
+        prize,
+    });
+
+    // the prize becomes claimable by the members with the accepted distribution
+    team.prize = prize;
+    team.result_reported = true;
+
+    emit!(ResultReported {
+        tournament: tournament.key(),
+        team: team_key,
+        placement,
+        prize,
+    });
+
+    Ok(())
+}
+
+// report result instruction
+#[derive(Accounts)]
+pub struct ReportResult<'info> {
+    #[account(mut, seeds=[b"tournament", tournament_account.organizer.as_ref(), tournament_account.name.as_bytes()], bump = tournament_account.bump)]
+    pub tournament_account: Account<'info, TournamentAccount>,
+
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    // prize vault of the team, the prize is released into it
+    #[account(mut, seeds=[b"vault", team_account.key().as_ref()], bump = team_account.vault_bump)]
+    pub vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+}
+
+// report result instruction for a team with a prize paid in spl tokens
+// the organizer deposits the prize to the team token vault before reporting it
+#[derive(Accounts)]
+pub struct ReportTokenResult<'info> {
+    #[account(mut, seeds=[b"tournament", tournament_account.organizer.as_ref(), tournament_account.name.as_bytes()], bump = tournament_account.bump)]
+    pub tournament_account: Account<'info, TournamentAccount>,
+
+    #[account(mut, seeds=[team_account.name.as_bytes(), &team_account.id.to_le_bytes()], bump = team_account.bump)]
+    pub team_account: Account<'info, TeamAccount>,
+
+    #[account(constraint = prize_mint.key() == team_account.prize_mint @ ErrorCode::InvalidPrizeMintError)]
+    pub prize_mint: Account<'info, Mint>,
+
+    // token vault of the team, the associated token account of the team pda
+    #[account(associated_token::mint = prize_mint, associated_token::authority = team_account)]
+    pub prize_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+}
@@ -3,6 +3,7 @@ import { Program } from "@project-serum/anchor";
 import { decodeIdlAccount } from "@project-serum/anchor/dist/cjs/idl";
 import { MethodsBuilder } from "@project-serum/anchor/dist/cjs/program/namespace/methods";
 import { assert } from "chai";
+import { createHash } from "crypto";
 import { TeamDao } from "../target/types/team_dao";
 
 describe("Distribution tests", () => {
@@ -18,12 +19,32 @@ describe("Distribution tests", () => {
 	const dan = anchor.web3.Keypair.generate();
 	let team = [alice, bob, carol, dan];
 
-	let tournament = anchor.web3.Keypair.generate();
+	let tournamentName = "Test Tournament 1";
+	let tournamentPda;
+	let startSlot: number;
 
 	const program = anchor.workspace.TeamDao as Program<TeamDao>;
 
 	let teamName = "Test Team 4";
-	let uid = new anchor.BN(1234567);
+	// assigned by the registry in before
+	let uid;
+	// sha256 hash of the normalized name, reserves the name of the team
+	let nameHash = [
+		...createHash("sha256").update(teamName.trim().toLowerCase()).digest(),
+	];
+	// simple majority for the quorum and the approval of the team votes
+	let governance = { quorumPercentage: 51, approvalPercentage: 51 };
+	// 5v5 team format
+	let rosterSize = 5;
+	// optional metadata of the team
+	let metadata = {
+		tag: null,
+		logoUri: null,
+		bannerUri: null,
+		game: null,
+		region: null,
+		socialLinks: [],
+	};
 	let teamAccountAddr;
 
 	let tournamentPrize = anchor.web3.LAMPORTS_PER_SOL * 100;
@@ -31,47 +52,192 @@ describe("Distribution tests", () => {
 	// the team addresses array
 
 	let teamPda, teamBump;
+	let vaultPda;
+
+	// basis points of the captain and the members, 10000 is the whole prize
+	let basisPoints = [3000, 1000, 2000, 1500, 2500];
+	let distributionPda;
+
+	// creates a proposal of the team with the next proposal id and returns its address
+	const createProposal = async (kind, payload: Buffer) => {
+		let { proposalCount } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		const [pda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("proposal"),
+				teamAccountAddr.toBuffer(),
+				proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.createProposal(kind, payload, new anchor.BN(60 * 60))
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: pda })
+			.rpc();
+
+		return pda;
+	};
+
+	// 3 yes votes of the 5 players pass the proposal
+	const passProposal = async (pda) => {
+		for (let i = 0; i < 3; i++) {
+			await program.methods
+				.castVote({ yes: {} })
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: pda,
+					signer: team[i].publicKey,
+				})
+				.signers([team[i]])
+				.rpc();
+		}
+	};
+
+	const executeProposal = async (pda) => {
+		await program.methods
+			.executeProposal()
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: pda })
+			.rpc();
+	};
+
+	// waits until the cluster reaches the slot, the tournament is finalized after its end slot
+	const waitForSlot = async (slot: number) => {
+		while ((await program.provider.connection.getSlot()) < slot) {
+			await new Promise((resolve) => setTimeout(resolve, 400));
+		}
+	};
+
+	// the borsh serialized tournament address and prize mint, sol prizes use the default pubkey
+	const joinPayload = (tournamentAddress: anchor.web3.PublicKey) =>
+		Buffer.concat([
+			tournamentAddress.toBuffer(),
+			anchor.web3.PublicKey.default.toBuffer(),
+		]);
+
+	// the borsh serialized distribution shares
+	const sharesPayload = (shares) => {
+		const length = Buffer.alloc(4);
+		length.writeUInt32LE(shares.length);
+		return Buffer.concat([
+			length,
+			...shares.map((share) => {
+				const basisPoints = Buffer.alloc(2);
+				basisPoints.writeUInt16LE(share.basisPoints);
+				return Buffer.concat([share.member.toBuffer(), basisPoints]);
+			}),
+		]);
+	};
 
 	before(async () => {
+		// creating the registry if this is the first test file that runs
+		const [registryPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("registry")],
+			program.programId
+		);
+		if (
+			(await program.provider.connection.getAccountInfo(registryPda)) == null
+		) {
+			await program.methods.initRegistry().rpc();
+		}
+		uid = (await program.account.registryAccount.fetch(registryPda)).teamCount;
+
 		// creating account here because i will use it in other tests
-		const ix = await program.methods.createTeam(teamName, uid);
+		const ix = await program.methods.createTeam(
+			teamName,
+			uid,
+			nameHash,
+			governance,
+			rosterSize,
+			rosterSize,
+			metadata
+		);
 		teamAccountAddr = (await ix.pubkeys()).teamAccount;
 		const tx = await ix.rpc();
 
 		[teamPda, teamBump] = await anchor.web3.PublicKey.findProgramAddress(
-			[Buffer.from(teamName), Buffer.from(`${uid}`)],
+			[Buffer.from(teamName), uid.toArrayLike(Buffer, "le", 8)],
 			program.programId
 		);
 
 		// adding team members
 		for (let i = 0; i < team.length; i++) {
-			await program.methods.addMember(teamName, uid, team[i].publicKey).rpc();
-		}
-
-		// initing tournament
-		await program.methods
-			.initTournament(
-				teamName,
-				uid,
-				tournament.publicKey,
-				new anchor.BN(tournamentPrize)
-			)
-			.rpc();
+			const [invitationPda] = await anchor.web3.PublicKey.findProgramAddress(
+				[
+					Buffer.from("invite"),
+					teamAccountAddr.toBuffer(),
+					team[i].publicKey.toBuffer(),
+				],
+				program.programId
+			);
 
-		// creating tournament
-		// voting for 3 members
-		for (let i = 0; i < 3; i++) {
+			// the captain invites the member and the member accepts the invitation
 			await program.methods
-				.voteForTournament(teamName, uid, { yes: {} })
+				.inviteMember(team[i].publicKey)
 				.accounts({
 					teamAccount: teamAccountAddr,
-					signer: team[i].publicKey,
-					systemProgram: anchor.web3.SystemProgram.programId,
+					invitationAccount: invitationPda,
+				})
+				.rpc();
+			await program.methods
+				.acceptInvite()
+				.accounts({
+					teamAccount: teamAccountAddr,
+					invitationAccount: invitationPda,
+					inviter: user.publicKey,
+					invitee: team[i].publicKey,
 				})
 				.signers([team[i]])
 				.rpc();
 		}
 
+		[vaultPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("vault"), teamAccountAddr.toBuffer()],
+			program.programId
+		);
+
+		// creating the tournament account and opening its registration
+		[tournamentPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("tournament"),
+				user.publicKey.toBuffer(),
+				Buffer.from(tournamentName),
+			],
+			program.programId
+		);
+
+		// the registration is open until the start slot, the votes before the entry fit in it
+		let slot = await program.provider.connection.getSlot();
+		startSlot = slot + 150;
+
+		await program.methods
+			.createTournament(
+				tournamentName,
+				new anchor.BN(0),
+				new anchor.BN(tournamentPrize),
+				new anchor.BN(startSlot),
+				new anchor.BN(startSlot + 1),
+				8,
+				user.publicKey,
+				rosterSize
+			)
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+
+		await program.methods
+			.openRegistration()
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+
+		// joining the tournament with a proposal voted by 3 members
+		const joinPda = await createProposal(
+			{ joinTournament: {} },
+			joinPayload(tournamentPda)
+		);
+		await passProposal(joinPda);
+		await executeProposal(joinPda);
+
 		await program.provider.connection.confirmTransaction(
 			await program.provider.connection.requestAirdrop(
 				alice.publicKey,
@@ -81,68 +247,341 @@ describe("Distribution tests", () => {
 	});
 
 	it("should init percentage proposal successfully", async () => {
-		let proposalPercentages = [30, 10, 20, 15, 25];
-		await program.methods
-			.initPercentageProposal(teamName, uid, Buffer.from(proposalPercentages)) // buffering the data
-			.rpc();
+		let members = [user.publicKey, ...team.map((member) => member.publicKey)];
+		let proposalShares = members.map((member, i) => ({
+			member,
+			basisPoints: basisPoints[i],
+		}));
+		distributionPda = await createProposal(
+			{ distribution: {} },
+			sharesPayload(proposalShares)
+		);
+
+		// the split is applied when the proposal is executed
+		const proposal = await program.account.proposalAccount.fetch(
+			distributionPda
+		);
+		assert.deepEqual(proposal.status, { active: {} });
+
+		let { distributionShares, distributionVotingResult } =
+			await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(distributionShares.length, 0);
+		assert.equal(distributionVotingResult, false);
+	});
+
+	it("should let players vote for percentages successfully", async () => {
+		await passProposal(distributionPda);
+		await executeProposal(distributionPda);
 
-		let { distributionPercentages: distPerc } =
+		let { distributionShares: distShares, distributionVotingResult } =
 			await program.account.teamAccount.fetch(teamAccountAddr);
 
-		// checking if the send array and the fetched array has the same values
-		let isArrayEqual = proposalPercentages.every(
-			(element, index) => element === distPerc[index]
+		// checking if the proposed shares and the fetched shares have the same values
+		let members = [user.publicKey, ...team.map((member) => member.publicKey)];
+		let isArrayEqual = members.every(
+			(member, index) =>
+				member.equals(distShares[index].member) &&
+				basisPoints[index] === distShares[index].basisPoints
 		);
 
 		assert.equal(isArrayEqual, true);
+		assert.equal(distributionVotingResult, true);
 	});
 
-	it("should let players vote for percentages successfully", async () => {
-		// voting for 3 members
-		for (let i = 0; i < 3; i++) {
+	it("should replace the split with a new distribution proposal", async () => {
+		let { distributionShares: distShares } =
+			await program.account.teamAccount.fetch(teamAccountAddr);
+
+		// the captain and alice swap their shares
+		let swapped = [basisPoints[1], basisPoints[0], ...basisPoints.slice(2)];
+		let newShares = distShares.map((share, i) => ({
+			member: share.member,
+			basisPoints: swapped[i],
+		}));
+		const newDistributionPda = await createProposal(
+			{ distribution: {} },
+			sharesPayload(newShares)
+		);
+
+		// the accepted split is kept while the new one is voted
+		let teamDetails = await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(teamDetails.distributionVotingResult, true);
+		assert.equal(teamDetails.distributionShares[0].basisPoints, basisPoints[0]);
+
+		await passProposal(newDistributionPda);
+		await executeProposal(newDistributionPda);
+
+		teamDetails = await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(teamDetails.distributionVotingResult, true);
+		assert.equal(teamDetails.distributionShares[0].basisPoints, basisPoints[1]);
+		assert.equal(teamDetails.distributionShares[1].basisPoints, basisPoints[0]);
+	});
+
+	it("should not let the team join the tournament without a lineup", async () => {
+		try {
 			await program.methods
-				.distributionProposalHandler(teamName, uid, { yes: {} })
+				.canJoinTournament()
 				.accounts({
 					teamAccount: teamAccountAddr,
-					signer: team[i].publicKey,
-					systemProgram: anchor.web3.SystemProgram.programId,
+					tournamentAccount: tournamentPda,
 				})
-				.signers([team[i]])
 				.rpc();
+			assert.fail("the team should not join without a lineup");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"The lineup of the team must have as many players as the roster size of the tournament"
+			);
+			assert.equal(err.error.errorCode.code, "NotEnoughPlayersError");
 		}
+	});
+
+	it("should set the lineup of the team successfully", async () => {
+		let lineup = [user.publicKey, ...team.map((member) => member.publicKey)];
+		await program.methods
+			.setLineup(lineup)
+			.accounts({
+				teamAccount: teamAccountAddr,
+				tournamentAccount: tournamentPda,
+			})
+			.rpc();
 
-		let { distributionVotingResult: distResult } =
+		let { lineup: fetchedLineup, lineupTournament } =
 			await program.account.teamAccount.fetch(teamAccountAddr);
 
-		assert.equal(distResult, true);
+		assert.equal(fetchedLineup.length, rosterSize);
+		assert.equal(lineupTournament.toBase58(), tournamentPda.toBase58());
 	});
 
 	it("should be able to set canJoinTournament successfully", async () => {
-		await program.methods.canJoinTournament(teamName, uid).rpc();
+		await program.methods
+			.canJoinTournament()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				tournamentAccount: tournamentPda,
+			})
+			.rpc();
 
 		let { canJoinTournament } = await program.account.teamAccount.fetch(
 			teamAccountAddr
 		);
 
 		assert.equal(canJoinTournament, true);
+
+		// the team must be registered to the tournament account
+		let { registeredTeams } = await program.account.tournamentAccount.fetch(
+			tournamentPda
+		);
+
+		assert.equal(registeredTeams[0].toBase58(), teamAccountAddr.toBase58());
+
+		// the roster and the distribution are recorded at the entry
+		let { entrySnapshot, members, distributionShares } =
+			await program.account.teamAccount.fetch(teamAccountAddr);
+
+		assert.equal(entrySnapshot.tournament.toBase58(), tournamentPda.toBase58());
+		assert.equal(entrySnapshot.members.length, members.length);
+		assert.equal(entrySnapshot.shares.length, distributionShares.length);
 	});
 
-	it("should distribute prizes successfully", async () => {
-		let reward;
+	it("should not change the split after the entry", async () => {
+		let members = [user.publicKey, ...team.map((member) => member.publicKey)];
+		try {
+			await createProposal(
+				{ distribution: {} },
+				sharesPayload(
+					members.map((member) => ({ member, basisPoints: 2000 }))
+				)
+			);
+			assert.fail("the split should be locked after the entry");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "RosterLockedError");
+		}
+	});
 
-		// airdrop for teamAccount
-		await program.provider.connection.confirmTransaction(
-			await program.provider.connection.requestAirdrop(
-				teamAccountAddr,
-				anchor.web3.LAMPORTS_PER_SOL * 101
-			)
+	it("should not let a member leave the team while the roster is locked", async () => {
+		let { rosterLocked } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		assert.equal(rosterLocked, true);
+
+		try {
+			await program.methods
+				.leaveTeam()
+				.accounts({ teamAccount: teamAccountAddr, signer: dan.publicKey })
+				.signers([dan])
+				.rpc();
+			assert.fail("the member should not leave a locked roster");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"The roster of the team is locked until the tournament is settled"
+			);
+			assert.equal(err.error.errorCode.code, "RosterLockedError");
+		}
+	});
+
+	it("should not let the team leave the tournament before its result", async () => {
+		const leavePda = await createProposal(
+			{ leaveTournament: {} },
+			Buffer.from([])
+		);
+		await passProposal(leavePda);
+
+		try {
+			await executeProposal(leavePda);
+			assert.fail("the registered team should wait for its result");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "RegisteredTournamentError");
+		}
+
+		let { activeTournament } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		assert.equal(activeTournament.toBase58(), tournamentPda.toBase58());
+	});
+
+	it("should replace a player of the locked roster with an outside player by vote", async () => {
+		const substitute = anchor.web3.Keypair.generate().publicKey;
+		// the borsh serialized outgoing and incoming player
+		const substitutionPayload = (
+			playerOut: anchor.web3.PublicKey,
+			playerIn: anchor.web3.PublicKey
+		) => Buffer.concat([playerOut.toBuffer(), playerIn.toBuffer()]);
+
+		let substitutionPda = await createProposal(
+			{ emergencySubstitution: {} },
+			substitutionPayload(dan.publicKey, substitute)
+		);
+		await passProposal(substitutionPda);
+		await executeProposal(substitutionPda);
+
+		// the outside player takes the spot of dan in the roster and in the lineup
+		let { members, lineup, rosterLocked } =
+			await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(rosterLocked, true);
+		assert.equal(members.length, rosterSize);
+		assert.equal(members[4].toBase58(), substitute.toBase58());
+		assert.equal(lineup[4].toBase58(), substitute.toBase58());
+		assert.isFalse(members.some((member) => member.equals(dan.publicKey)));
+		assert.isFalse(lineup.some((player) => player.equals(dan.publicKey)));
+
+		// dan comes back for the rest of the tournament
+		substitutionPda = await createProposal(
+			{ emergencySubstitution: {} },
+			substitutionPayload(substitute, dan.publicKey)
+		);
+		await passProposal(substitutionPda);
+		await executeProposal(substitutionPda);
+
+		({ members, lineup } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		));
+		assert.equal(members[4].toBase58(), dan.publicKey.toBase58());
+		assert.equal(lineup[4].toBase58(), dan.publicKey.toBase58());
+	});
+
+	it("should release the reported prize into the team vault", async () => {
+		await program.methods
+			.closeRegistration()
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+
+		await waitForSlot(startSlot + 1);
+		await program.methods
+			.finalizeTournament()
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+
+		let vaultBalance = await program.provider.connection.getBalance(vaultPda);
+
+		await program.methods
+			.reportResult(1, new anchor.BN(tournamentPrize))
+			.accounts({
+				tournamentAccount: tournamentPda,
+				teamAccount: teamAccountAddr,
+				vault: vaultPda,
+				signer: user.publicKey,
+			})
+			.rpc();
+
+		let vaultBalanceAfter = await program.provider.connection.getBalance(
+			vaultPda
+		);
+
+		assert.equal(vaultBalanceAfter, vaultBalance + tournamentPrize);
+
+		let { prize, resultReported } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+
+		assert.equal(prize.toNumber(), tournamentPrize);
+		assert.equal(resultReported, true);
+	});
+
+	it("should not init a new tournament before the prize is claimed", async () => {
+		// the team leaves the tournament with the prize still in the vault
+		const leavePda = await createProposal(
+			{ leaveTournament: {} },
+			Buffer.from([])
+		);
+		await passProposal(leavePda);
+		await executeProposal(leavePda);
+
+		let { activeTournament } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		assert.equal(
+			activeTournament.toBase58(),
+			anchor.web3.PublicKey.default.toBase58()
+		);
+
+		const joinPda = await createProposal(
+			{ joinTournament: {} },
+			joinPayload(tournamentPda)
 		);
+		await passProposal(joinPda);
+
+		try {
+			await executeProposal(joinPda);
+			assert.fail("the unclaimed prize should not be reset");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"The prize of the team is not fully claimed"
+			);
+			assert.equal(err.error.errorCode.code, "UnclaimedPrizeError");
+		}
+	});
 
-		// get the balance of teamAccount
-		let teamAccountBalance = await program.provider.connection.getBalance(
+	it("should keep the shares of the entry when the roster changes", async () => {
+		let { entrySnapshot } = await program.account.teamAccount.fetch(
 			teamAccountAddr
 		);
 
+		// the roster is unlocked once the tournament is left, dan leaves with an unclaimed share
+		await program.methods
+			.leaveTeam()
+			.accounts({ teamAccount: teamAccountAddr, signer: dan.publicKey })
+			.signers([dan])
+			.rpc();
+
+		let teamDetails = await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(teamDetails.members.length, rosterSize - 1);
+		// the claims are still resolved against the roster and the split of the entry
+		assert.deepEqual(
+			teamDetails.entrySnapshot.members.map((member) => member.toBase58()),
+			entrySnapshot.members.map((member) => member.toBase58())
+		);
+		assert.deepEqual(
+			teamDetails.entrySnapshot.shares.map((share) => share.basisPoints),
+			entrySnapshot.shares.map((share) => share.basisPoints)
+		);
+	});
+
+	it("should distribute prizes successfully", async () => {
+		let reward;
+
 		// air drop for user
 		await program.provider.connection.confirmTransaction(
 			await program.provider.connection.requestAirdrop(
@@ -151,9 +590,10 @@ describe("Distribution tests", () => {
 			)
 		);
 
-		// getting the distribution percentage of team[i] from program
-		let { distributionPercentages: distPerc } =
-			await program.account.teamAccount.fetch(teamAccountAddr);
+		// getting the distribution share of team[i] recorded at the entry from program
+		let {
+			entrySnapshot: { shares: distShares },
+		} = await program.account.teamAccount.fetch(teamAccountAddr);
 
 		// get the balance of user
 		let userBalance =
@@ -161,13 +601,13 @@ describe("Distribution tests", () => {
 			anchor.web3.LAMPORTS_PER_SOL;
 
 		// calculating the reward for user
-		reward = (tournamentPrize * distPerc[0]) / 100;
+		reward = (tournamentPrize * distShares[0].basisPoints) / 10000;
 
 		await program.methods
-			.claimReward(teamName, uid, new anchor.BN(reward))
+			.claimReward(new anchor.BN(reward))
 			.accounts({
 				teamAccount: teamAccountAddr,
-				from: teamAccountAddr,
+				vault: vaultPda,
 				to: user.publicKey,
 				user: user.publicKey,
 				systemProgram: anchor.web3.SystemProgram.programId,
@@ -199,14 +639,14 @@ describe("Distribution tests", () => {
 				team[i].publicKey
 			);
 
-			reward = (tournamentPrize * distPerc[i + 1]) / 100; // i + 1 because the first element of the array is user account that i used above
+			reward = (tournamentPrize * distShares[i + 1].basisPoints) / 10000; // i + 1 because the first element of the array is user account that i used above
 
-			// claim the reward for alice
+			// claim the reward for the member, dan claims after leaving the team
 			await program.methods
-				.claimReward(teamName, uid, new anchor.BN(reward))
+				.claimReward(new anchor.BN(reward))
 				.accounts({
 					teamAccount: teamAccountAddr,
-					from: teamAccountAddr,
+					vault: vaultPda,
 					to: team[i].publicKey,
 					user: team[i].publicKey,
 					systemProgram: anchor.web3.SystemProgram.programId,
@@ -222,4 +662,114 @@ describe("Distribution tests", () => {
 			assert.equal(teamMemberBalanceAfterTx, teamMemberBalance + reward);
 		}
 	});
+
+	it("should not let a member claim the reward twice", async () => {
+		let { distributionShares: distShares } =
+			await program.account.teamAccount.fetch(teamAccountAddr);
+
+		let reward = (tournamentPrize * distShares[1].basisPoints) / 10000;
+
+		try {
+			await program.methods
+				.claimReward(new anchor.BN(reward))
+				.accounts({
+					teamAccount: teamAccountAddr,
+					vault: vaultPda,
+					to: team[0].publicKey,
+					user: team[0].publicKey,
+					systemProgram: anchor.web3.SystemProgram.programId,
+				})
+				.signers([team[0]])
+				.rpc();
+			assert.fail("the second claim should fail");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"The reward exceeds the unclaimed share of the member"
+			);
+			assert.equal(err.error.errorCode.code, "RewardAlreadyClaimedError");
+		}
+	});
+
+	it("should reject a claim that overflows the claimed amount", async () => {
+		try {
+			await program.methods
+				.claimReward(new anchor.BN("18446744073709551615")) // u64 max
+				.accounts({
+					teamAccount: teamAccountAddr,
+					vault: vaultPda,
+					to: team[0].publicKey,
+					user: team[0].publicKey,
+					systemProgram: anchor.web3.SystemProgram.programId,
+				})
+				.signers([team[0]])
+				.rpc();
+			assert.fail("the overflowing claim should fail");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "RewardAlreadyClaimedError");
+		}
+	});
+
+	it("should settle the prize by distributing the rewards", async () => {
+		let { distributionShares: distShares } =
+			await program.account.teamAccount.fetch(teamAccountAddr);
+
+		// the member accounts in the order of the distribution shares
+		let memberAccounts = distShares.map((share) => ({
+			pubkey: share.member,
+			isSigner: false,
+			isWritable: true,
+		}));
+
+		try {
+			await program.methods
+				.distributeRewards()
+				.accounts({ teamAccount: teamAccountAddr, vault: vaultPda })
+				.remainingAccounts([...memberAccounts].reverse())
+				.rpc();
+			assert.fail("the member accounts should be rejected");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "InvalidMemberAccountsError");
+		}
+
+		let vaultBalance = await program.provider.connection.getBalance(vaultPda);
+
+		await program.methods
+			.distributeRewards()
+			.accounts({ teamAccount: teamAccountAddr, vault: vaultPda })
+			.remainingAccounts(memberAccounts)
+			.rpc();
+
+		// every reward is already claimed, nothing is paid and the prize is settled
+		let vaultBalanceAfter = await program.provider.connection.getBalance(
+			vaultPda
+		);
+		let { prizeSettled, activeTournament, rosterLocked } =
+			await program.account.teamAccount.fetch(teamAccountAddr);
+
+		assert.equal(vaultBalanceAfter, vaultBalance);
+		assert.equal(prizeSettled, true);
+		// the team is released from the tournament
+		assert.equal(
+			activeTournament.toBase58(),
+			"11111111111111111111111111111111" // Pubkey::default()
+		);
+		assert.equal(rosterLocked, false);
+	});
+
+	it("should init the next tournament once the prize is settled", async () => {
+		const joinPda = await createProposal(
+			{ joinTournament: {} },
+			joinPayload(tournamentPda)
+		);
+		await passProposal(joinPda);
+		await executeProposal(joinPda);
+
+		let teamDetails = await program.account.teamAccount.fetch(teamAccountAddr);
+
+		// the prize, the claims and the distribution vote of the previous tournament are reset
+		assert.equal(teamDetails.resultReported, false);
+		assert.equal(teamDetails.claimedRewards.length, 0);
+		assert.equal(teamDetails.distributionVotingResult, false);
+	});
 });
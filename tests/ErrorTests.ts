@@ -3,6 +3,7 @@ import { Program } from "@project-serum/anchor";
 import { decodeIdlAccount } from "@project-serum/anchor/dist/cjs/idl";
 import { MethodsBuilder } from "@project-serum/anchor/dist/cjs/program/namespace/methods";
 import { assert } from "chai";
+import { createHash } from "crypto";
 import { TeamDao } from "../target/types/team_dao";
 
 describe("Error tests", () => {
@@ -22,7 +23,25 @@ describe("Error tests", () => {
 	const program = anchor.workspace.TeamDao as Program<TeamDao>;
 
 	let teamName = "Test Team 3";
-	let uid = new anchor.BN(1234567);
+	// assigned by the registry in before
+	let uid;
+	// sha256 hash of the normalized name, reserves the name of the team
+	let nameHash = [
+		...createHash("sha256").update(teamName.trim().toLowerCase()).digest(),
+	];
+	// simple majority for the quorum and the approval of the team votes
+	let governance = { quorumPercentage: 51, approvalPercentage: 51 };
+	// 5v5 team format
+	let rosterSize = 5;
+	// optional metadata of the team
+	let metadata = {
+		tag: null,
+		logoUri: null,
+		bannerUri: null,
+		game: null,
+		region: null,
+		socialLinks: [],
+	};
 	let teamAccountAddr;
 
 	// the team addresses array
@@ -30,32 +49,112 @@ describe("Error tests", () => {
 
 	let teamPda, teamBump;
 
+	let proposalPda;
+
 	before(async () => {
+		// creating the registry if this is the first test file that runs
+		const [registryPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("registry")],
+			program.programId
+		);
+		if (
+			(await program.provider.connection.getAccountInfo(registryPda)) == null
+		) {
+			await program.methods.initRegistry().rpc();
+		}
+		uid = (await program.account.registryAccount.fetch(registryPda)).teamCount;
+
 		// creating account here because i will use it in other tests
-		const ix = await program.methods.createTeam(teamName, uid);
+		const ix = await program.methods.createTeam(
+			teamName,
+			uid,
+			nameHash,
+			governance,
+			rosterSize,
+			rosterSize,
+			metadata
+		);
 		teamAccountAddr = (await ix.pubkeys()).teamAccount;
 		const tx = await ix.rpc();
 
 		[teamPda, teamBump] = await anchor.web3.PublicKey.findProgramAddress(
-			[Buffer.from(teamName), Buffer.from(`${uid}`)],
+			[Buffer.from(teamName), uid.toArrayLike(Buffer, "le", 8)],
 			program.programId
 		);
 
 		// adding team members
 		// only 4 member added to the team because i ll test  the last one for captain error
 		for (let i = 0; i < team.length - 1; i++) {
-			await program.methods.addMember(teamName, uid, team[i].publicKey).rpc();
+			const [invitationPda] = await anchor.web3.PublicKey.findProgramAddress(
+				[
+					Buffer.from("invite"),
+					teamAccountAddr.toBuffer(),
+					team[i].publicKey.toBuffer(),
+				],
+				program.programId
+			);
+
+			// the captain invites the member and the member accepts the invitation
+			await program.methods
+				.inviteMember(team[i].publicKey)
+				.accounts({
+					teamAccount: teamAccountAddr,
+					invitationAccount: invitationPda,
+				})
+				.rpc();
+			await program.methods
+				.acceptInvite()
+				.accounts({
+					teamAccount: teamAccountAddr,
+					invitationAccount: invitationPda,
+					inviter: user.publicKey,
+					invitee: team[i].publicKey,
+				})
+				.signers([team[i]])
+				.rpc();
 		}
 
-		// initing tournament
+		let { proposalCount } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		[proposalPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("proposal"),
+				teamAccountAddr.toBuffer(),
+				proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		// proposing to join the tournament with a sol prize
 		await program.methods
-			.initTournament(teamName, uid, tournament.publicKey, new anchor.BN(100))
+			.createProposal(
+				{ joinTournament: {} },
+				Buffer.concat([
+					tournament.publicKey.toBuffer(),
+					anchor.web3.PublicKey.default.toBuffer(),
+				]),
+				new anchor.BN(60 * 60)
+			)
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: proposalPda })
 			.rpc();
 	});
 
 	it("should not let a player vote twice", async () => {
+		await program.methods
+			.castVote({ yes: {} })
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: proposalPda })
+			.rpc();
+
 		try {
-			await program.methods.voteForTournament(teamName, uid, { yes: {} }).rpc();
+			await program.methods
+				.castVote({ yes: {} })
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: proposalPda,
+				})
+				.rpc();
+			assert.fail("the second vote should fail");
 		} catch (err) {
 			assert.equal(
 				err.error.errorMessage,
@@ -69,26 +168,37 @@ describe("Error tests", () => {
 		let anotherUser = anchor.web3.Keypair.generate();
 		try {
 			await program.methods
-				.voteForTournament(teamName, uid, { yes: {} })
+				.castVote({ yes: {} })
 				.accounts({
 					teamAccount: teamAccountAddr,
+					proposalAccount: proposalPda,
 					signer: anotherUser.publicKey,
-					systemProgram: anchor.web3.SystemProgram.programId,
 				})
 				.signers([anotherUser])
 				.rpc();
+			assert.fail("the outsider should not vote");
 		} catch (err) {
 			assert.equal(err.error.errorMessage, "Member is not in the team");
 			assert.equal(err.error.errorCode.code, "MemberNotInTeamError");
 		}
 	});
 
-	it("should not let anyone else other than captain add member", async () => {
+	it("should not let anyone else other than captain invite a member", async () => {
+		const [invitationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("invite"),
+				teamAccountAddr.toBuffer(),
+				dan.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
 		try {
 			await program.methods
-				.addMember(teamName, uid, dan.publicKey)
+				.inviteMember(dan.publicKey)
 				.accounts({
 					teamAccount: teamAccountAddr,
+					invitationAccount: invitationPda,
 					signer: alice.publicKey,
 					systemProgram: anchor.web3.SystemProgram.programId,
 				})
@@ -97,9 +207,125 @@ describe("Error tests", () => {
 		} catch (err) {
 			assert.equal(
 				err.error.errorMessage,
-				"Only captain can call this function"
+				"The member does not have the permission for this action"
+			);
+			assert.equal(err.error.errorCode.code, "PermissionDeniedError");
+		}
+	});
+
+	it("should not create a team with an invalid governance config", async () => {
+		try {
+			await program.methods
+				.createTeam(
+					"Test Team 6",
+					uid.addn(1),
+					[...createHash("sha256").update("test team 6").digest()],
+					{ quorumPercentage: 0, approvalPercentage: 101 },
+					rosterSize,
+					rosterSize,
+					metadata
+				)
+				.rpc();
+			assert.fail("the governance config should be rejected");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"Quorum and approval percentages must be between 1 and 100"
+			);
+			assert.equal(err.error.errorCode.code, "InvalidGovernanceConfigError");
+		}
+	});
+
+	it("should not create a team with an invalid roster size", async () => {
+		try {
+			// the minimum roster size cannot exceed the maximum
+			await program.methods
+				.createTeam(
+					"Test Team 7",
+					uid.addn(1),
+					[...createHash("sha256").update("test team 7").digest()],
+					governance,
+					6,
+					5,
+					metadata
+				)
+				.rpc();
+			assert.fail("the roster size should be rejected");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"Roster size must be between 1 and 12 and the minimum cannot exceed the maximum"
 			);
-			assert.equal(err.error.errorCode.code, "NotCaptainError");
+			assert.equal(err.error.errorCode.code, "InvalidRosterSizeError");
+		}
+	});
+
+	it("should not create a team with a name that is already used", async () => {
+		// the names are compared case insensitive and without the surrounding whitespaces
+		try {
+			await program.methods
+				.createTeam(
+					" TEST TEAM 3 ",
+					uid.addn(1),
+					nameHash,
+					governance,
+					rosterSize,
+					rosterSize,
+					metadata
+				)
+				.rpc();
+			assert.fail("the name should already be reserved");
+		} catch (err) {
+			assert.isTrue(err.logs.some((log) => log.includes("already in use")));
+		}
+	});
+
+	it("should not create a team with an id that is not assigned by the registry", async () => {
+		try {
+			await program.methods
+				.createTeam(
+					"Test Team 8",
+					uid.addn(2),
+					[...createHash("sha256").update("test team 8").digest()],
+					governance,
+					rosterSize,
+					rosterSize,
+					metadata
+				)
+				.rpc();
+			assert.fail("the team id should be rejected");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"The team id must be the next id of the registry"
+			);
+			assert.equal(err.error.errorCode.code, "InvalidTeamIdError");
+		}
+	});
+
+	it("should not migrate a team that already has the current layout", async () => {
+		try {
+			await program.methods.migrateTeam(teamName, uid, nameHash).rpc();
+			assert.fail("the team should already be migrated");
+		} catch (err) {
+			// a team in the current layout already has the metadata that the migration creates
+			assert.isTrue(err.logs.some((log) => log.includes("already in use")));
+		}
+	});
+
+	it("should not finalize a proposal before its voting period is over", async () => {
+		try {
+			await program.methods
+				.finalizeProposal()
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: proposalPda,
+				})
+				.rpc();
+			assert.fail("the proposal should still be open");
+		} catch (err) {
+			assert.equal(err.error.errorMessage, "The voting period is not over yet");
+			assert.equal(err.error.errorCode.code, "VoteNotExpiredError");
 		}
 	});
 });
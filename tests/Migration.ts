@@ -0,0 +1,146 @@
+import * as anchor from "@project-serum/anchor";
+import { Program } from "@project-serum/anchor";
+import { assert } from "chai";
+import { createHash } from "crypto";
+import { TeamDao } from "../target/types/team_dao";
+
+describe("Migration tests", () => {
+	// Configure the client to use the local cluster.
+	const provider = anchor.AnchorProvider.env();
+	anchor.setProvider(provider);
+
+	const program = anchor.workspace.TeamDao as Program<TeamDao>;
+
+	// the legacy team is loaded from tests/fixtures/legacy_team.json by the validator
+	// it has 2 members, a 60/40 split and an open tournament vote with the yes vote of the captain
+	let teamName = "Legacy Team";
+	let teamId = new anchor.BN(1000);
+	let nameHash = [
+		...createHash("sha256").update(teamName.trim().toLowerCase()).digest(),
+	];
+	let teamAccountAddr = new anchor.web3.PublicKey(
+		"5qGFq5TpLdW69EexwAXwVm9Qkkefd5btBUcfqa5FLvDH"
+	);
+	let captain = new anchor.web3.PublicKey(
+		"8GGFUpA7BXe1DPufh6vB9oJ6obwMQ1zt3bCGk5aWH4A7"
+	);
+	let member = new anchor.web3.PublicKey(
+		"FSvshZq6kGR1hsWZeoHB4d5PW5BZByDuombmKsUTVQQX"
+	);
+	// size of every legacy team account
+	let legacySize = 612;
+
+	let registryPda;
+
+	before(async () => {
+		// creating the registry if this is the first test file that runs
+		[registryPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("registry")],
+			program.programId
+		);
+		if (
+			(await program.provider.connection.getAccountInfo(registryPda)) == null
+		) {
+			await program.methods.initRegistry().rpc();
+		}
+	});
+
+	it("should migrate a legacy team account", async () => {
+		let legacyAccount = await program.provider.connection.getAccountInfo(
+			teamAccountAddr
+		);
+		assert.equal(legacyAccount.data.length, legacySize);
+
+		let { teamCount } = await program.account.registryAccount.fetch(
+			registryPda
+		);
+
+		await program.methods.migrateTeam(teamName, teamId, nameHash).rpc();
+
+		let team = await program.account.teamAccount.fetch(teamAccountAddr);
+
+		// the legacy fields are kept
+		assert.equal(team.version, 1);
+		assert.equal(team.name, teamName);
+		assert.equal(team.id.toNumber(), teamId.toNumber());
+		assert.equal(team.captain.toBase58(), captain.toBase58());
+		assert.deepEqual(
+			team.members.map((x) => x.toBase58()),
+			[captain.toBase58(), member.toBase58()]
+		);
+		// the legacy tournament vote was not decided, the tournament is dropped
+		// and the team proposes it again
+		assert.equal(
+			team.activeTournament.toBase58(),
+			anchor.web3.PublicKey.default.toBase58()
+		);
+		// the percentages are converted to basis points keyed by the member
+		assert.equal(
+			team.distributionShares[0].member.toBase58(),
+			captain.toBase58()
+		);
+		assert.equal(team.distributionShares[0].basisPoints, 6000);
+		assert.equal(
+			team.distributionShares[1].member.toBase58(),
+			member.toBase58()
+		);
+		assert.equal(team.distributionShares[1].basisPoints, 4000);
+
+		// the new fields get the defaults of a legacy team
+		assert.equal(team.minRosterSize, 5);
+		assert.equal(team.maxRosterSize, 5);
+		assert.equal(team.governance.quorumPercentage, 51);
+		assert.equal(team.governance.approvalPercentage, 51);
+		assert.equal(team.councilThreshold, 1);
+		assert.equal(team.council.length, 0);
+		assert.equal(team.resultReported, false);
+		assert.equal(team.rosterLocked, false);
+
+		// the split still in its vote needs a distribution proposal to be accepted
+		assert.equal(team.distributionVotingResult, false);
+
+		// the grown account is rent exempt
+		let migratedAccount = await program.provider.connection.getAccountInfo(
+			teamAccountAddr
+		);
+		assert.isAbove(migratedAccount.data.length, legacySize);
+		assert.isAtLeast(
+			migratedAccount.lamports,
+			await program.provider.connection.getMinimumBalanceForRentExemption(
+				migratedAccount.data.length
+			)
+		);
+
+		// the team gets a vault, a metadata, a name reservation and the next index of the registry
+		const [vaultPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("vault"), teamAccountAddr.toBuffer()],
+			program.programId
+		);
+		assert.isAtLeast(
+			await program.provider.connection.getBalance(vaultPda),
+			await program.provider.connection.getMinimumBalanceForRentExemption(0)
+		);
+		const [metadataPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("metadata"), teamAccountAddr.toBuffer()],
+			program.programId
+		);
+		let metadataAccount = await program.account.teamMetadataAccount.fetch(
+			metadataPda
+		);
+		assert.equal(metadataAccount.team.toBase58(), teamAccountAddr.toBase58());
+		const [nameReservationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("name"), Buffer.from(nameHash)],
+			program.programId
+		);
+		let nameReservation = await program.account.nameReservationAccount.fetch(
+			nameReservationPda
+		);
+		assert.equal(nameReservation.index.toNumber(), teamCount.toNumber());
+		const [teamIndexPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("team_index"), teamCount.toArrayLike(Buffer, "le", 8)],
+			program.programId
+		);
+		let teamIndex = await program.account.teamIndexAccount.fetch(teamIndexPda);
+		assert.equal(teamIndex.team.toBase58(), teamAccountAddr.toBase58());
+	});
+});
@@ -0,0 +1,576 @@
+import * as anchor from "@project-serum/anchor";
+import { Program } from "@project-serum/anchor";
+import { assert } from "chai";
+import { createHash } from "crypto";
+import { TeamDao } from "../target/types/team_dao";
+
+describe("Proposal tests", () => {
+	// Configure the client to use the local cluster.
+	const provider = anchor.AnchorProvider.env();
+	anchor.setProvider(provider);
+
+	const user = provider.wallet;
+
+	const alice = anchor.web3.Keypair.generate();
+	const bob = anchor.web3.Keypair.generate();
+	const carol = anchor.web3.Keypair.generate();
+	const dan = anchor.web3.Keypair.generate();
+
+	let tournament = anchor.web3.Keypair.generate();
+
+	const program = anchor.workspace.TeamDao as Program<TeamDao>;
+
+	let teamName = "Test Team 5";
+	// assigned by the registry in before
+	let uid;
+	// sha256 hash of the normalized name, reserves the name of the team
+	let nameHash = [
+		...createHash("sha256").update(teamName.trim().toLowerCase()).digest(),
+	];
+	// simple majority for the quorum and the approval of the team votes
+	let governance = { quorumPercentage: 51, approvalPercentage: 51 };
+	// 5v5 team format
+	let rosterSize = 5;
+	// optional metadata of the team
+	let metadata = {
+		tag: null,
+		logoUri: null,
+		bannerUri: null,
+		game: null,
+		region: null,
+		socialLinks: [],
+	};
+	let teamAccountAddr;
+
+	// the team addresses array
+	let team = [alice, bob, carol, dan];
+
+	let proposalPda;
+
+	// creates a proposal of the team with the next proposal id and returns its address
+	const createProposal = async (kind, payload: Buffer, proposer = null) => {
+		let { proposalCount } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		const [pda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("proposal"),
+				teamAccountAddr.toBuffer(),
+				proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.createProposal(kind, payload, new anchor.BN(60 * 60))
+			.accounts({
+				teamAccount: teamAccountAddr,
+				proposalAccount: pda,
+				signer: proposer ? proposer.publicKey : user.publicKey,
+			})
+			.signers(proposer ? [proposer] : [])
+			.rpc();
+
+		return pda;
+	};
+
+	// creates a proposal of the team and passes it with the votes of 3 members
+	const passProposal = async (kind, payload: Buffer, proposer = null) => {
+		const pda = await createProposal(kind, payload, proposer);
+
+		for (let i = 0; i < 3; i++) {
+			await program.methods
+				.castVote({ yes: {} })
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: pda,
+					signer: team[i].publicKey,
+				})
+				.signers([team[i]])
+				.rpc();
+		}
+
+		return pda;
+	};
+
+	// the borsh serialized distribution shares
+	const sharesPayload = (shares) => {
+		const length = Buffer.alloc(4);
+		length.writeUInt32LE(shares.length);
+		return Buffer.concat([
+			length,
+			...shares.map((share) => {
+				const basisPoints = Buffer.alloc(2);
+				basisPoints.writeUInt16LE(share.basisPoints);
+				return Buffer.concat([share.member.toBuffer(), basisPoints]);
+			}),
+		]);
+	};
+
+	before(async () => {
+		// creating the registry if this is the first test file that runs
+		const [registryPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("registry")],
+			program.programId
+		);
+		if (
+			(await program.provider.connection.getAccountInfo(registryPda)) == null
+		) {
+			await program.methods.initRegistry().rpc();
+		}
+		uid = (await program.account.registryAccount.fetch(registryPda)).teamCount;
+
+		// creating account here because i will use it in other tests
+		const ix = await program.methods.createTeam(
+			teamName,
+			uid,
+			nameHash,
+			governance,
+			rosterSize,
+			rosterSize,
+			metadata
+		);
+		teamAccountAddr = (await ix.pubkeys()).teamAccount;
+		const tx = await ix.rpc();
+
+		// adding team members
+		for (let i = 0; i < team.length; i++) {
+			const [invitationPda] = await anchor.web3.PublicKey.findProgramAddress(
+				[
+					Buffer.from("invite"),
+					teamAccountAddr.toBuffer(),
+					team[i].publicKey.toBuffer(),
+				],
+				program.programId
+			);
+
+			// the captain invites the member and the member accepts the invitation
+			await program.methods
+				.inviteMember(team[i].publicKey)
+				.accounts({
+					teamAccount: teamAccountAddr,
+					invitationAccount: invitationPda,
+				})
+				.rpc();
+			await program.methods
+				.acceptInvite()
+				.accounts({
+					teamAccount: teamAccountAddr,
+					invitationAccount: invitationPda,
+					inviter: user.publicKey,
+					invitee: team[i].publicKey,
+				})
+				.signers([team[i]])
+				.rpc();
+		}
+
+		let { proposalCount } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+
+		[proposalPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("proposal"),
+				teamAccountAddr.toBuffer(),
+				proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		// proposing to join the tournament, the payload is the borsh serialized
+		// tournament address and prize mint, sol prizes use the default pubkey
+		await program.methods
+			.createProposal(
+				{ joinTournament: {} },
+				Buffer.concat([
+					tournament.publicKey.toBuffer(),
+					anchor.web3.PublicKey.default.toBuffer(),
+				]),
+				new anchor.BN(60 * 60)
+			)
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: proposalPda })
+			.rpc();
+	});
+
+	it("should create a proposal successfully", async () => {
+		const proposal = await program.account.proposalAccount.fetch(proposalPda);
+
+		assert.equal(proposal.team.toBase58(), teamAccountAddr.toBase58());
+		assert.equal(proposal.proposer.toBase58(), user.publicKey.toBase58());
+		assert.deepEqual(proposal.status, { active: {} });
+	});
+
+	it("should not create a proposal with a voting period longer than 30 days", async () => {
+		let { proposalCount } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		const [nextProposalPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("proposal"),
+				teamAccountAddr.toBuffer(),
+				proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		try {
+			await program.methods
+				.createProposal(
+					{ leaveTournament: {} },
+					Buffer.from([]),
+					new anchor.BN(30 * 24 * 60 * 60 + 1)
+				)
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: nextProposalPda,
+				})
+				.rpc();
+			assert.fail("the voting period should be rejected");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"The voting period must be positive and at most 30 days"
+			);
+			assert.equal(err.error.errorCode.code, "InvalidVotingPeriodError");
+		}
+	});
+
+	it("should not let a member vote twice for a proposal", async () => {
+		await program.methods
+			.castVote({ abstain: {} })
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: proposalPda })
+			.rpc();
+
+		try {
+			await program.methods
+				.castVote({ yes: {} })
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: proposalPda,
+				})
+				.rpc();
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"Member is already voted for the tournament"
+			);
+			assert.equal(err.error.errorCode.code, "AlreadyVotedError");
+		}
+	});
+
+	it("should pass the proposal when the majority votes yes", async () => {
+		for (let i = 0; i < 3; i++) {
+			await program.methods
+				.castVote({ yes: {} })
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: proposalPda,
+					signer: team[i].publicKey,
+				})
+				.signers([team[i]])
+				.rpc();
+		}
+
+		const proposal = await program.account.proposalAccount.fetch(proposalPda);
+
+		assert.equal(proposal.yesVotes, 3);
+		assert.equal(proposal.abstainVotes, 1);
+		assert.deepEqual(proposal.status, { passed: {} });
+	});
+
+	it("should execute the passed proposal successfully", async () => {
+		await program.methods
+			.executeProposal()
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: proposalPda })
+			.rpc();
+
+		const proposal = await program.account.proposalAccount.fetch(proposalPda);
+		assert.deepEqual(proposal.status, { executed: {} });
+
+		const teamDetails = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		assert.equal(
+			teamDetails.activeTournament.toBase58(),
+			tournament.publicKey.toBase58()
+		);
+	});
+
+	it("should elect a council with a team vote", async () => {
+		let { proposalCount } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+
+		const [councilProposalPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("proposal"),
+				teamAccountAddr.toBuffer(),
+				proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		// borsh serialized council config, one co-captain and 2 approvals
+		const members = Buffer.alloc(4);
+		members.writeUInt32LE(1);
+		const payload = Buffer.concat([
+			members,
+			team[0].publicKey.toBuffer(),
+			Buffer.from([2]),
+		]);
+
+		await program.methods
+			.createProposal({ updateCouncil: {} }, payload, new anchor.BN(60 * 60))
+			.accounts({
+				teamAccount: teamAccountAddr,
+				proposalAccount: councilProposalPda,
+			})
+			.rpc();
+
+		for (let i = 0; i < 3; i++) {
+			await program.methods
+				.castVote({ yes: {} })
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: councilProposalPda,
+					signer: team[i].publicKey,
+				})
+				.signers([team[i]])
+				.rpc();
+		}
+
+		await program.methods
+			.executeProposal()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				proposalAccount: councilProposalPda,
+			})
+			.rpc();
+
+		const teamDetails = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		assert.equal(teamDetails.council.length, 1);
+		assert.equal(
+			teamDetails.council[0].toBase58(),
+			team[0].publicKey.toBase58()
+		);
+		assert.equal(teamDetails.councilThreshold, 2);
+	});
+
+	it("should require the council threshold for the sensitive actions", async () => {
+		let shares = [
+			{ member: user.publicKey, basisPoints: 4000 },
+			{ member: team[0].publicKey, basisPoints: 3000 },
+			{ member: team[1].publicKey, basisPoints: 3000 },
+		];
+
+		const distributionPda = await passProposal(
+			{ distribution: {} },
+			sharesPayload(shares)
+		);
+
+		try {
+			// the captain alone is not enough anymore to execute the passed distribution
+			await program.methods
+				.executeProposal()
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: distributionPda,
+				})
+				.rpc();
+			assert.fail("the council threshold should not be reached");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"Not enough council members approved the action"
+			);
+			assert.equal(err.error.errorCode.code, "CouncilApprovalError");
+		}
+
+		// the co-captain signs the same instruction
+		await program.methods
+			.executeProposal()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				proposalAccount: distributionPda,
+			})
+			.remainingAccounts([
+				{ pubkey: team[0].publicKey, isSigner: true, isWritable: false },
+			])
+			.signers([team[0]])
+			.rpc();
+
+		const teamDetails = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		assert.deepEqual(
+			teamDetails.distributionShares.map((share) => share.basisPoints),
+			[4000, 3000, 3000]
+		);
+	});
+
+	it("should not count a signer outside the council as an approval", async () => {
+		// a manager can init distributions but is not a council member
+		await program.methods
+			.setMemberRole(team[1].publicKey, { manager: {} })
+			.accounts({ teamAccount: teamAccountAddr })
+			.rpc();
+
+		let shares = [
+			{ member: user.publicKey, basisPoints: 5000 },
+			{ member: team[1].publicKey, basisPoints: 5000 },
+		];
+
+		// the manager proposes the distribution
+		const distributionPda = await passProposal(
+			{ distribution: {} },
+			sharesPayload(shares),
+			team[1]
+		);
+
+		try {
+			// the manager and one co-captain do not reach the threshold of 2
+			await program.methods
+				.executeProposal()
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: distributionPda,
+					signer: team[1].publicKey,
+				})
+				.remainingAccounts([
+					{ pubkey: team[0].publicKey, isSigner: true, isWritable: false },
+				])
+				.signers([team[1], team[0]])
+				.rpc();
+			assert.fail("the manager should not count as a council approval");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "CouncilApprovalError");
+		}
+	});
+
+	it("should require the council threshold to open the team to join", async () => {
+		try {
+			await program.methods
+				.setOpenToJoin(true)
+				.accounts({ teamAccount: teamAccountAddr })
+				.rpc();
+			assert.fail("the council threshold should not be reached");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "CouncilApprovalError");
+		}
+	});
+
+	it("should not let a coach vote for a distribution proposal", async () => {
+		// a coach votes for tournaments and proposals but not for the prize distribution
+		await program.methods
+			.setMemberRole(team[2].publicKey, { coach: {} })
+			.accounts({ teamAccount: teamAccountAddr })
+			.rpc();
+
+		const distributionPda = await createProposal(
+			{ distribution: {} },
+			sharesPayload([
+				{ member: user.publicKey, basisPoints: 5000 },
+				{ member: team[0].publicKey, basisPoints: 5000 },
+			])
+		);
+
+		try {
+			await program.methods
+				.castVote({ yes: {} })
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: distributionPda,
+					signer: team[2].publicKey,
+				})
+				.signers([team[2]])
+				.rpc();
+			assert.fail("the coach should not vote for the distribution");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"The member does not have the permission for this action"
+			);
+			assert.equal(err.error.errorCode.code, "PermissionDeniedError");
+		}
+
+		// the coach does not count for the quorum of the distribution either,
+		// 3 yes votes of the 4 voters pass it
+		for (let i = 0; i < 2; i++) {
+			await program.methods
+				.castVote({ yes: {} })
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: distributionPda,
+					signer: team[i].publicKey,
+				})
+				.signers([team[i]])
+				.rpc();
+		}
+		await program.methods
+			.castVote({ yes: {} })
+			.accounts({
+				teamAccount: teamAccountAddr,
+				proposalAccount: distributionPda,
+			})
+			.rpc();
+
+		const proposal = await program.account.proposalAccount.fetch(
+			distributionPda
+		);
+		assert.deepEqual(proposal.status, { passed: {} });
+	});
+
+	it("should require the council threshold to disband the team", async () => {
+		const [metadataPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("metadata"), teamAccountAddr.toBuffer()],
+			program.programId
+		);
+		const [nameReservationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("name"), Buffer.from(nameHash)],
+			program.programId
+		);
+		const [teamIndexPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("team_index"), uid.toArrayLike(Buffer, "le", 8)],
+			program.programId
+		);
+
+		try {
+			// the captain alone does not reach the threshold of 2
+			await program.methods
+				.disbandTeam()
+				.accounts({
+					teamAccount: teamAccountAddr,
+					metadataAccount: metadataPda,
+					nameReservationAccount: nameReservationPda,
+					teamIndexAccount: teamIndexPda,
+				})
+				.rpc();
+			assert.fail("the council threshold should not be reached");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "CouncilApprovalError");
+		}
+	});
+
+	it("should require the council threshold to join a tournament", async () => {
+		const joinPda = await passProposal(
+			{ joinTournament: {} },
+			Buffer.concat([
+				anchor.web3.Keypair.generate().publicKey.toBuffer(),
+				anchor.web3.PublicKey.default.toBuffer(),
+			])
+		);
+
+		try {
+			// the passed proposal still needs the approval of the council
+			await program.methods
+				.executeProposal()
+				.accounts({ teamAccount: teamAccountAddr, proposalAccount: joinPda })
+				.rpc();
+			assert.fail("the council threshold should not be reached");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "CouncilApprovalError");
+		}
+	});
+});
@@ -0,0 +1,113 @@
+import * as anchor from "@project-serum/anchor";
+import { Program } from "@project-serum/anchor";
+import { assert } from "chai";
+import { TeamDao } from "../target/types/team_dao";
+
+describe("Reward distribution tests", () => {
+	// Configure the client to use the local cluster.
+	const provider = anchor.AnchorProvider.env();
+	anchor.setProvider(provider);
+
+	const program = anchor.workspace.TeamDao as Program<TeamDao>;
+
+	// the team and its vault are loaded from tests/fixtures/short_vault_team.json and
+	// tests/fixtures/short_vault.json by the validator
+	// the team is registered with 3 members and a 50/30/20 split, its reported prize of 1 sol
+	// is not claimed yet and the vault holds only about half of it
+	let teamAccountAddr = new anchor.web3.PublicKey(
+		"8pt6T41NLKC15a2wmZbTU5jjLwu4b2TkdQjnjMMBhjpe"
+	);
+	let vaultPda = new anchor.web3.PublicKey(
+		"2QFSLzn5X7mPWdQtFDy1Fa9rNP1ETMzajTyAdi1aN922"
+	);
+	let tournament = new anchor.web3.PublicKey(
+		"HRHjtknLWWCmHPum62XBnTuhBYavrCi5NnTMQpuDpjV8"
+	);
+	let members = [
+		"ACEYjUCZqGoPDg1XA1UkUBn6wShfQcAKinC1DA7894tt",
+		"G25gasYj2oMaE4q662nbKCi8o8EvYjJMq3AdFGCSrJ2p",
+		"Bf8B7sbPrTUnHL93LHFeLqo7Tby5V5TAJVp1K7PosmT9",
+	].map((member) => new anchor.web3.PublicKey(member));
+
+	let prize = anchor.web3.LAMPORTS_PER_SOL;
+	// lamports of the vault above its rent exempt minimum
+	let available = 500_000_003;
+
+	it("should scale the rewards down to the vault and leave the dust in it", async () => {
+		let { prizeSettled, distributionShares } =
+			await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(prizeSettled, false);
+
+		let balances = await Promise.all(
+			members.map((member) => program.provider.connection.getBalance(member))
+		);
+		let vaultBalance = await program.provider.connection.getBalance(vaultPda);
+
+		let distributed;
+		let dust;
+		let distributedTournament;
+		const listener = program.addEventListener(
+			"RewardsDistributed",
+			(event) => {
+				distributed = event.distributed.toNumber();
+				dust = event.dust.toNumber();
+				distributedTournament = event.tournament;
+			}
+		);
+
+		await program.methods
+			.distributeRewards()
+			.accounts({ teamAccount: teamAccountAddr, vault: vaultPda })
+			.remainingAccounts(
+				members.map((member) => ({
+					pubkey: member,
+					isSigner: false,
+					isWritable: true,
+				}))
+			)
+			.rpc();
+
+		// every member gets its share of the available lamports, rounded down
+		let expectedRewards = distributionShares.map((share) =>
+			new anchor.BN(prize)
+				.muln(share.basisPoints)
+				.divn(10000)
+				.mul(new anchor.BN(available))
+				.div(new anchor.BN(prize))
+				.toNumber()
+		);
+		assert.deepEqual(expectedRewards, [250_000_001, 150_000_000, 100_000_000]);
+
+		for (let i = 0; i < members.length; i++) {
+			let balance = await program.provider.connection.getBalance(members[i]);
+			assert.equal(balance - balances[i], expectedRewards[i]);
+		}
+
+		// the rounding dust stays in the vault
+		let totalRewards = expectedRewards.reduce((a, b) => a + b, 0);
+		let vaultBalanceAfter = await program.provider.connection.getBalance(
+			vaultPda
+		);
+		assert.equal(vaultBalanceAfter, vaultBalance - totalRewards);
+
+		await new Promise((resolve) => setTimeout(resolve, 1000));
+		await program.removeEventListener(listener);
+		assert.equal(distributed, totalRewards);
+		assert.equal(dust, available - totalRewards);
+		assert.equal(dust, 2);
+		assert.equal(distributedTournament.toBase58(), tournament.toBase58());
+
+		// the prize is settled and the team is released from the tournament
+		let teamDetails = await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(teamDetails.prizeSettled, true);
+		assert.equal(
+			teamDetails.activeTournament.toBase58(),
+			anchor.web3.PublicKey.default.toBase58()
+		);
+		assert.equal(teamDetails.rosterLocked, false);
+		assert.deepEqual(
+			teamDetails.claimedRewards.map((claim) => claim.amount.toNumber()),
+			expectedRewards
+		);
+	});
+});
@@ -2,6 +2,7 @@ import * as anchor from "@project-serum/anchor";
 import { Program } from "@project-serum/anchor";
 import { MethodsBuilder } from "@project-serum/anchor/dist/cjs/program/namespace/methods";
 import { assert } from "chai";
+import { createHash } from "crypto";
 import { TeamDao } from "../target/types/team_dao";
 
 describe("Team CRUD tests", () => {
@@ -14,23 +15,63 @@ describe("Team CRUD tests", () => {
 	const alice = anchor.web3.Keypair.generate();
 	const bob = anchor.web3.Keypair.generate();
 	const carol = anchor.web3.Keypair.generate();
+	// the captain of the team after the captain role is transferred
+	const newMember = anchor.web3.Keypair.generate();
 
 	const program = anchor.workspace.TeamDao as Program<TeamDao>;
 
 	let teamName = "Test Team 1";
-	let uid = new anchor.BN(1234567890);
+	// assigned by the registry in before
+	let uid;
+	// sha256 hash of the normalized name, reserves the name of the team
+	let nameHash = [
+		...createHash("sha256").update(teamName.trim().toLowerCase()).digest(),
+	];
+	// simple majority for the quorum and the approval of the team votes
+	let governance = { quorumPercentage: 51, approvalPercentage: 51 };
+	// 5v5 team format
+	let rosterSize = 5;
+	// optional metadata of the team
+	let metadata = {
+		tag: null,
+		logoUri: null,
+		bannerUri: null,
+		game: null,
+		region: null,
+		socialLinks: [],
+	};
 	let teamAccountAddr;
 
 	let teamPda, teamBump;
 
 	before(async () => {
+		// creating the registry if this is the first test file that runs
+		const [registryPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("registry")],
+			program.programId
+		);
+		if (
+			(await program.provider.connection.getAccountInfo(registryPda)) == null
+		) {
+			await program.methods.initRegistry().rpc();
+		}
+		uid = (await program.account.registryAccount.fetch(registryPda)).teamCount;
+
 		[teamPda, teamBump] = await anchor.web3.PublicKey.findProgramAddress(
-			[Buffer.from(teamName), Buffer.from(`${uid}`)],
+			[Buffer.from(teamName), uid.toArrayLike(Buffer, "le", 8)],
 			program.programId
 		);
 
 		// creating account here because i will use it in other tests
-		const ix = await program.methods.createTeam(teamName, uid);
+		const ix = await program.methods.createTeam(
+			teamName,
+			uid,
+			nameHash,
+			governance,
+			rosterSize,
+			rosterSize,
+			metadata
+		);
 		teamAccountAddr = (await ix.pubkeys()).teamAccount;
 
 		const tx = await ix.rpc();
@@ -42,23 +83,334 @@ describe("Team CRUD tests", () => {
 		);
 
 		assert.equal(teamAccount.name, teamName);
+		// the id is a little endian seed of the team account
+		assert.equal(teamAccountAddr.toBase58(), teamPda.toBase58());
+		assert.equal(teamAccount.governance.quorumPercentage, 51);
+		assert.equal(teamAccount.governance.approvalPercentage, 51);
 	});
 
-	it("should add a member to the team", async () => {
+	it("should register the team in the registry", async () => {
+		const [teamIndexPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("team_index"), uid.toArrayLike(Buffer, "le", 8)],
+			program.programId
+		);
+		const [nameReservationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("name"), Buffer.from(nameHash)],
+			program.programId
+		);
+
+		const teamIndex = await program.account.teamIndexAccount.fetch(teamIndexPda);
+		const nameReservation =
+			await program.account.nameReservationAccount.fetch(nameReservationPda);
+
+		assert.equal(teamIndex.team.toBase58(), teamAccountAddr.toBase58());
+		assert.equal(nameReservation.team.toBase58(), teamAccountAddr.toBase58());
+		assert.equal(nameReservation.index.toNumber(), uid.toNumber());
+	});
+
+	it("should update the metadata of the team", async () => {
+		const [metadataPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("metadata"), teamAccountAddr.toBuffer()],
+			program.programId
+		);
+
+		await program.methods
+			.updateTeamMetadata({
+				...metadata,
+				tag: "TT1",
+				game: "valorant",
+				region: "eu-west",
+			})
+			.accounts({ teamAccount: teamAccountAddr, metadataAccount: metadataPda })
+			.rpc();
+
+		const metadataAccount = await program.account.teamMetadataAccount.fetch(
+			metadataPda
+		);
+
+		assert.equal(metadataAccount.team.toBase58(), teamAccountAddr.toBase58());
+		assert.equal(metadataAccount.metadata.tag, "TT1");
+		assert.equal(metadataAccount.metadata.game, "valorant");
+		assert.equal(metadataAccount.metadata.region, "eu-west");
+	});
+
+	it("should invite a member and add them when they accept", async () => {
 		let teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
 
 		let teamLength = teamAccount.members.length;
 
-		const ix = await program.methods.addMember(teamName, uid, bob.publicKey);
-		const tx = await ix.rpc();
+		const [invitationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("invite"),
+				teamAccountAddr.toBuffer(),
+				bob.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.inviteMember(bob.publicKey)
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+			})
+			.rpc();
+
+		// the member is not added before accepting the invitation
+		teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(teamAccount.members.length, teamLength);
+
+		await program.methods
+			.acceptInvite()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+				inviter: user.publicKey,
+				invitee: bob.publicKey,
+			})
+			.signers([bob])
+			.rpc();
+
+		teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
+
+		assert.equal(teamAccount.members.length, teamLength + 1);
+		assert.equal(
+			teamAccount.members[teamAccount.members.length - 1].toBase58(),
+			bob.publicKey.toBase58()
+		);
+	});
+
+	it("should not add a member that declines the invitation", async () => {
+		let teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
+
+		let teamLength = teamAccount.members.length;
+
+		const [invitationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("invite"),
+				teamAccountAddr.toBuffer(),
+				carol.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.inviteMember(carol.publicKey)
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+			})
+			.rpc();
+
+		await program.methods
+			.declineInvite()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+				inviter: user.publicKey,
+				invitee: carol.publicKey,
+			})
+			.signers([carol])
+			.rpc();
+
+		teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(teamAccount.members.length, teamLength);
+
+		// the invitation is closed
+		const invitation = await program.provider.connection.getAccountInfo(
+			invitationPda
+		);
+		assert.equal(invitation, null);
+	});
+
+	it("should let the captain revoke an invitation", async () => {
+		const [invitationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("invite"),
+				teamAccountAddr.toBuffer(),
+				alice.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.inviteMember(alice.publicKey)
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+			})
+			.rpc();
+
+		await program.methods
+			.revokeInvite()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+				inviter: user.publicKey,
+			})
+			.rpc();
+
+		const invitation = await program.provider.connection.getAccountInfo(
+			invitationPda
+		);
+		assert.equal(invitation, null);
+	});
+
+	it("should add a member when the captain approves the join request", async () => {
+		let applicant = anchor.web3.Keypair.generate();
+
+		await program.provider.connection.confirmTransaction(
+			await program.provider.connection.requestAirdrop(
+				applicant.publicKey,
+				anchor.web3.LAMPORTS_PER_SOL
+			)
+		);
+
+		const [joinRequestPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("join_request"),
+				teamAccountAddr.toBuffer(),
+				applicant.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.requestToJoin()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				joinRequestAccount: joinRequestPda,
+				signer: applicant.publicKey,
+			})
+			.signers([applicant])
+			.rpc();
+
+		let teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
+		let teamLength = teamAccount.members.length;
+
+		await program.methods
+			.approveJoinRequest()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				joinRequestAccount: joinRequestPda,
+				applicant: applicant.publicKey,
+			})
+			.rpc();
 
 		teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
 
 		assert.equal(teamAccount.members.length, teamLength + 1);
 		assert.equal(
 			teamAccount.members[teamAccount.members.length - 1].toBase58(),
+			applicant.publicKey.toBase58()
+		);
+	});
+
+	it("should accept the join request of an open team automatically", async () => {
+		let applicant = anchor.web3.Keypair.generate();
+
+		await program.provider.connection.confirmTransaction(
+			await program.provider.connection.requestAirdrop(
+				applicant.publicKey,
+				anchor.web3.LAMPORTS_PER_SOL
+			)
+		);
+
+		await program.methods
+			.setOpenToJoin(true)
+			.accounts({ teamAccount: teamAccountAddr })
+			.rpc();
+
+		const [joinRequestPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("join_request"),
+				teamAccountAddr.toBuffer(),
+				applicant.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.requestToJoin()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				joinRequestAccount: joinRequestPda,
+				signer: applicant.publicKey,
+			})
+			.signers([applicant])
+			.rpc();
+
+		let teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(
+			teamAccount.members[teamAccount.members.length - 1].toBase58(),
+			applicant.publicKey.toBase58()
+		);
+
+		await program.methods
+			.setOpenToJoin(false)
+			.accounts({ teamAccount: teamAccountAddr })
+			.rpc();
+	});
+
+	it("should let a manager invite members", async () => {
+		await program.provider.connection.confirmTransaction(
+			await program.provider.connection.requestAirdrop(
+				bob.publicKey,
+				anchor.web3.LAMPORTS_PER_SOL
+			)
+		);
+
+		await program.methods
+			.setMemberRole(bob.publicKey, { manager: {} })
+			.accounts({ teamAccount: teamAccountAddr })
+			.rpc();
+
+		let teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(
+			teamAccount.memberRoles[0].member.toBase58(),
 			bob.publicKey.toBase58()
 		);
+		assert.deepEqual(teamAccount.memberRoles[0].role, { manager: {} });
+
+		const [invitationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("invite"),
+				teamAccountAddr.toBuffer(),
+				carol.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		// the manager role has the invite member permission by default
+		// the manager is not in the council, so the captain approves the invitation
+		await program.methods
+			.inviteMember(carol.publicKey)
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+				signer: bob.publicKey,
+			})
+			.remainingAccounts([
+				{ pubkey: user.publicKey, isSigner: true, isWritable: false },
+			])
+			.signers([bob])
+			.rpc();
+
+		const invitation = await program.account.invitationAccount.fetch(
+			invitationPda
+		);
+		assert.equal(invitation.inviter.toBase58(), bob.publicKey.toBase58());
+
+		await program.methods
+			.revokeInvite()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+				inviter: bob.publicKey,
+				signer: bob.publicKey,
+			})
+			.signers([bob])
+			.rpc();
 	});
 
 	it("should remove a member from the team", async () => {
@@ -66,31 +418,59 @@ describe("Team CRUD tests", () => {
 
 		let teamLength = teamAccount.members.length;
 
-		const ix = await program.methods.removeMember(teamName, uid, bob.publicKey);
+		// the removal is also emitted as an event for the indexers
+		let removedMember;
+		const listener = program.addEventListener("MemberRemoved", (event) => {
+			removedMember = event.member;
+		});
+
+		const ix = await program.methods
+			.removeMember(bob.publicKey)
+			.accounts({ teamAccount: teamAccountAddr });
 		const tx = await ix.rpc();
 
 		teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
 
 		assert.equal(teamAccount.members.length, teamLength - 1);
+
+		await new Promise((resolve) => setTimeout(resolve, 1000));
+		await program.removeEventListener(listener);
+		assert.equal(removedMember.toBase58(), bob.publicKey.toBase58());
 	});
 
 	it("should transfer the captain role of the team", async () => {
 		let teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
 
-		let newMember = anchor.web3.Keypair.generate();
-
-		const ix = await program.methods.addMember(
-			teamName,
-			uid,
-			newMember.publicKey
+		const [invitationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("invite"),
+				teamAccountAddr.toBuffer(),
+				newMember.publicKey.toBuffer(),
+			],
+			program.programId
 		);
-		const tx = await ix.rpc();
 
-		const ix2 = await program.methods.transferCaptain(
-			teamName,
-			uid,
-			newMember.publicKey
-		);
+		await program.methods
+			.inviteMember(newMember.publicKey)
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+			})
+			.rpc();
+		await program.methods
+			.acceptInvite()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+				inviter: user.publicKey,
+				invitee: newMember.publicKey,
+			})
+			.signers([newMember])
+			.rpc();
+
+		const ix2 = await program.methods
+			.transferCaptain(newMember.publicKey)
+			.accounts({ teamAccount: teamAccountAddr });
 		const tx2 = await ix2.rpc();
 
 		teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
@@ -106,11 +486,55 @@ describe("Team CRUD tests", () => {
 
 		let teamLength = teamAccount.members.length;
 
-		const ix = await program.methods.leaveTeam(teamName, uid);
+		const ix = await program.methods
+			.leaveTeam()
+			.accounts({ teamAccount: teamAccountAddr });
 		const tx = await ix.rpc();
 
 		teamAccount = await program.account.teamAccount.fetch(teamAccountAddr);
 
 		assert.equal(teamAccount.members.length, teamLength - 1);
 	});
+
+	it("should disband the team and close its account", async () => {
+		const [vaultPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("vault"), teamAccountAddr.toBuffer()],
+			program.programId
+		);
+		const [metadataPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("metadata"), teamAccountAddr.toBuffer()],
+			program.programId
+		);
+		const [nameReservationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("name"), Buffer.from(nameHash)],
+			program.programId
+		);
+		const [teamIndexPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("team_index"), uid.toArrayLike(Buffer, "le", 8)],
+			program.programId
+		);
+
+		await program.methods
+			.disbandTeam()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				vault: vaultPda,
+				metadataAccount: metadataPda,
+				nameReservationAccount: nameReservationPda,
+				teamIndexAccount: teamIndexPda,
+				signer: newMember.publicKey,
+			})
+			.signers([newMember])
+			.rpc();
+
+		const teamAccount = await program.provider.connection.getAccountInfo(
+			teamAccountAddr
+		);
+		assert.equal(teamAccount, null);
+		// the name of the team is free again
+		const nameReservation = await program.provider.connection.getAccountInfo(
+			nameReservationPda
+		);
+		assert.equal(nameReservation, null);
+	});
 });
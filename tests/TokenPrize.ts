@@ -0,0 +1,512 @@
+import * as anchor from "@project-serum/anchor";
+import { Program } from "@project-serum/anchor";
+import {
+	createMint,
+	getAccount,
+	getOrCreateAssociatedTokenAccount,
+	mintTo,
+} from "@solana/spl-token";
+import { assert } from "chai";
+import { createHash } from "crypto";
+import { TeamDao } from "../target/types/team_dao";
+
+describe("Token prize tests", () => {
+	// Configure the client to use the local cluster.
+	const provider = anchor.AnchorProvider.env();
+	anchor.setProvider(provider);
+
+	const user = provider.wallet;
+	const payer = (provider.wallet as anchor.Wallet).payer;
+
+	const alice = anchor.web3.Keypair.generate();
+
+	let tournamentName = "Test Tournament 3";
+	let tournamentPda;
+	let startSlot: number;
+
+	const program = anchor.workspace.TeamDao as Program<TeamDao>;
+
+	let teamName = "Test Team 9";
+	// assigned by the registry in before
+	let uid;
+	// sha256 hash of the normalized name, reserves the name of the team
+	let nameHash = [
+		...createHash("sha256").update(teamName.trim().toLowerCase()).digest(),
+	];
+	// simple majority for the quorum and the approval of the team votes
+	let governance = { quorumPercentage: 51, approvalPercentage: 51 };
+	// 2v2 team format
+	let rosterSize = 2;
+	// optional metadata of the team
+	let metadata = {
+		tag: null,
+		logoUri: null,
+		bannerUri: null,
+		game: null,
+		region: null,
+		socialLinks: [],
+	};
+	let teamAccountAddr;
+
+	// prize in the smallest unit of the mint
+	let tournamentPrize = 1000;
+
+	let prizeMint;
+	// token vault of the team and the token account of alice
+	let prizeVault;
+	let aliceTokenAccount;
+
+	// waits until the cluster reaches the slot, the tournament is finalized after its end slot
+	const waitForSlot = async (slot: number) => {
+		while ((await program.provider.connection.getSlot()) < slot) {
+			await new Promise((resolve) => setTimeout(resolve, 400));
+		}
+	};
+
+	// creates a proposal of the team, passes it with the votes of both members and executes it
+	const passProposal = async (kind, payload: Buffer) => {
+		let { proposalCount } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		const [proposalPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("proposal"),
+				teamAccountAddr.toBuffer(),
+				proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.createProposal(kind, payload, new anchor.BN(60 * 60))
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: proposalPda })
+			.rpc();
+		await program.methods
+			.castVote({ yes: {} })
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: proposalPda })
+			.rpc();
+		await program.methods
+			.castVote({ yes: {} })
+			.accounts({
+				teamAccount: teamAccountAddr,
+				proposalAccount: proposalPda,
+				signer: alice.publicKey,
+			})
+			.signers([alice])
+			.rpc();
+		await program.methods
+			.executeProposal()
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: proposalPda })
+			.rpc();
+	};
+
+	before(async () => {
+		// creating the registry if this is the first test file that runs
+		const [registryPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("registry")],
+			program.programId
+		);
+		if (
+			(await program.provider.connection.getAccountInfo(registryPda)) == null
+		) {
+			await program.methods.initRegistry().rpc();
+		}
+		uid = (await program.account.registryAccount.fetch(registryPda)).teamCount;
+
+		const ix = await program.methods.createTeam(
+			teamName,
+			uid,
+			nameHash,
+			governance,
+			rosterSize,
+			rosterSize,
+			metadata
+		);
+		teamAccountAddr = (await ix.pubkeys()).teamAccount;
+		await ix.rpc();
+
+		await program.provider.connection.confirmTransaction(
+			await program.provider.connection.requestAirdrop(
+				alice.publicKey,
+				anchor.web3.LAMPORTS_PER_SOL
+			)
+		);
+
+		// the captain invites alice and alice accepts the invitation
+		const [invitationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("invite"),
+				teamAccountAddr.toBuffer(),
+				alice.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+		await program.methods
+			.inviteMember(alice.publicKey)
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+			})
+			.rpc();
+		await program.methods
+			.acceptInvite()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				invitationAccount: invitationPda,
+				inviter: user.publicKey,
+				invitee: alice.publicKey,
+			})
+			.signers([alice])
+			.rpc();
+
+		// the prize mint, the team token vault is owned by the team pda
+		prizeMint = await createMint(
+			program.provider.connection,
+			payer,
+			user.publicKey,
+			null,
+			0
+		);
+		prizeVault = (
+			await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				payer,
+				prizeMint,
+				teamAccountAddr,
+				true
+			)
+		).address;
+		aliceTokenAccount = (
+			await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				payer,
+				prizeMint,
+				alice.publicKey
+			)
+		).address;
+
+		// creating the tournament account and opening its registration
+		[tournamentPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("tournament"),
+				user.publicKey.toBuffer(),
+				Buffer.from(tournamentName),
+			],
+			program.programId
+		);
+
+		// the registration is open until the start slot, the votes before the entry fit in it
+		let slot = await program.provider.connection.getSlot();
+		startSlot = slot + 150;
+
+		await program.methods
+			.createTournament(
+				tournamentName,
+				new anchor.BN(0),
+				new anchor.BN(0),
+				new anchor.BN(startSlot),
+				new anchor.BN(startSlot + 1),
+				8,
+				user.publicKey,
+				rosterSize
+			)
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+
+		await program.methods
+			.openRegistration()
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+	});
+
+	it("should init a tournament with a token prize", async () => {
+		// both members vote for the tournament, the payload is the tournament and the prize mint
+		await passProposal(
+			{ joinTournament: {} },
+			Buffer.concat([tournamentPda.toBuffer(), prizeMint.toBuffer()])
+		);
+
+		let { activeTournament, prize, prizeMint: teamPrizeMint } =
+			await program.account.teamAccount.fetch(teamAccountAddr);
+
+		assert.equal(activeTournament.toBase58(), tournamentPda.toBase58());
+		assert.equal(teamPrizeMint.toBase58(), prizeMint.toBase58());
+		// the prize is only set when the result is reported
+		assert.equal(prize.toNumber(), 0);
+	});
+
+	it("should register the team to the tournament", async () => {
+		// the prize is split in half, the payload is the borsh serialized shares
+		const shares = Buffer.alloc(4);
+		shares.writeUInt32LE(2);
+		const half = Buffer.alloc(2);
+		half.writeUInt16LE(5000);
+		await passProposal(
+			{ distribution: {} },
+			Buffer.concat([
+				shares,
+				user.publicKey.toBuffer(),
+				half,
+				alice.publicKey.toBuffer(),
+				half,
+			])
+		);
+
+		await program.methods
+			.setLineup([user.publicKey, alice.publicKey])
+			.accounts({
+				teamAccount: teamAccountAddr,
+				tournamentAccount: tournamentPda,
+			})
+			.rpc();
+		await program.methods
+			.canJoinTournament()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				tournamentAccount: tournamentPda,
+			})
+			.rpc();
+
+		let { registeredTeams } = await program.account.tournamentAccount.fetch(
+			tournamentPda
+		);
+
+		assert.equal(registeredTeams[0].toBase58(), teamAccountAddr.toBase58());
+	});
+
+	it("should not let a member claim before the result is reported", async () => {
+		try {
+			await program.methods
+				.claimTokenReward(new anchor.BN(1))
+				.accounts({
+					teamAccount: teamAccountAddr,
+					prizeMint,
+					prizeVault,
+					to: alice.publicKey,
+					toTokenAccount: aliceTokenAccount,
+					user: alice.publicKey,
+				})
+				.signers([alice])
+				.rpc();
+			assert.fail("the claim should wait for the result");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "ResultNotReportedError");
+		}
+	});
+
+	it("should report the result once the prize is deposited", async () => {
+		await program.methods
+			.closeRegistration()
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+		await waitForSlot(startSlot + 1);
+		await program.methods
+			.finalizeTournament()
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+
+		try {
+			// the prize is not deposited to the team vault yet
+			await program.methods
+				.reportTokenResult(1, new anchor.BN(tournamentPrize))
+				.accounts({
+					tournamentAccount: tournamentPda,
+					teamAccount: teamAccountAddr,
+					prizeMint,
+					prizeVault,
+				})
+				.rpc();
+			assert.fail("the prize should be deposited first");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "VaultNotFundedError");
+		}
+
+		await mintTo(
+			program.provider.connection,
+			payer,
+			prizeMint,
+			prizeVault,
+			payer,
+			tournamentPrize
+		);
+
+		await program.methods
+			.reportTokenResult(1, new anchor.BN(tournamentPrize))
+			.accounts({
+				tournamentAccount: tournamentPda,
+				teamAccount: teamAccountAddr,
+				prizeMint,
+				prizeVault,
+			})
+			.rpc();
+
+		let { prize, resultReported } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+
+		assert.equal(prize.toNumber(), tournamentPrize);
+		assert.equal(resultReported, true);
+	});
+
+	it("should let a member claim its token reward", async () => {
+		await program.methods
+			.claimTokenReward(new anchor.BN(tournamentPrize / 2))
+			.accounts({
+				teamAccount: teamAccountAddr,
+				prizeMint,
+				prizeVault,
+				to: alice.publicKey,
+				toTokenAccount: aliceTokenAccount,
+				user: alice.publicKey,
+			})
+			.signers([alice])
+			.rpc();
+
+		let aliceTokens = await getAccount(
+			program.provider.connection,
+			aliceTokenAccount
+		);
+
+		assert.equal(Number(aliceTokens.amount), tournamentPrize / 2);
+	});
+
+	it("should not let a member claim more than its share", async () => {
+		try {
+			await program.methods
+				.claimTokenReward(new anchor.BN(1))
+				.accounts({
+					teamAccount: teamAccountAddr,
+					prizeMint,
+					prizeVault,
+					to: alice.publicKey,
+					toTokenAccount: aliceTokenAccount,
+					user: alice.publicKey,
+				})
+				.signers([alice])
+				.rpc();
+			assert.fail("the claim should exceed the share");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"The reward exceeds the unclaimed share of the member"
+			);
+			assert.equal(err.error.errorCode.code, "RewardAlreadyClaimedError");
+		}
+	});
+
+	it("should release the tournament once the last reward is claimed", async () => {
+		const userTokenAccount = (
+			await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				payer,
+				prizeMint,
+				user.publicKey
+			)
+		).address;
+
+		await program.methods
+			.claimTokenReward(new anchor.BN(tournamentPrize / 2))
+			.accounts({
+				teamAccount: teamAccountAddr,
+				prizeMint,
+				prizeVault,
+				to: user.publicKey,
+				toTokenAccount: userTokenAccount,
+				user: user.publicKey,
+			})
+			.rpc();
+
+		let { activeTournament, rosterLocked } =
+			await program.account.teamAccount.fetch(teamAccountAddr);
+
+		assert.equal(
+			activeTournament.toBase58(),
+			anchor.web3.PublicKey.default.toBase58()
+		);
+		assert.equal(rosterLocked, false);
+	});
+
+	it("should close the token vault before the team is disbanded", async () => {
+		const [metadataPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("metadata"), teamAccountAddr.toBuffer()],
+			program.programId
+		);
+		const [nameReservationPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("name"), Buffer.from(nameHash)],
+			program.programId
+		);
+		const [teamIndexPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("team_index"), uid.toArrayLike(Buffer, "le", 8)],
+			program.programId
+		);
+		const disband = () =>
+			program.methods
+				.disbandTeam()
+				.accounts({
+					teamAccount: teamAccountAddr,
+					metadataAccount: metadataPda,
+					nameReservationAccount: nameReservationPda,
+					teamIndexAccount: teamIndexPda,
+				})
+				.rpc();
+
+		try {
+			await disband();
+			assert.fail("the token vault should be closed first");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "TokenVaultNotClosedError");
+		}
+
+		// a token left in the vault is swept to the captain with the vault
+		await mintTo(
+			program.provider.connection,
+			payer,
+			prizeMint,
+			prizeVault,
+			payer,
+			1
+		);
+		const userTokenAccount = (
+			await getOrCreateAssociatedTokenAccount(
+				program.provider.connection,
+				payer,
+				prizeMint,
+				user.publicKey
+			)
+		).address;
+		let userTokens = await getAccount(
+			program.provider.connection,
+			userTokenAccount
+		);
+
+		await program.methods
+			.closeTokenVault()
+			.accounts({
+				teamAccount: teamAccountAddr,
+				prizeMint,
+				prizeVault,
+				toTokenAccount: userTokenAccount,
+			})
+			.rpc();
+
+		let userTokensAfter = await getAccount(
+			program.provider.connection,
+			userTokenAccount
+		);
+		assert.equal(
+			Number(userTokensAfter.amount),
+			Number(userTokens.amount) + 1
+		);
+		assert.equal(
+			await program.provider.connection.getAccountInfo(prizeVault),
+			null
+		);
+
+		await disband();
+
+		assert.equal(
+			await program.provider.connection.getAccountInfo(teamAccountAddr),
+			null
+		);
+	});
+});
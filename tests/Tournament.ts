@@ -0,0 +1,172 @@
+import * as anchor from "@project-serum/anchor";
+import { Program } from "@project-serum/anchor";
+import { assert } from "chai";
+import { TeamDao } from "../target/types/team_dao";
+
+describe("Tournament tests", () => {
+	// Configure the client to use the local cluster.
+	const provider = anchor.AnchorProvider.env();
+	anchor.setProvider(provider);
+
+	const user = provider.wallet;
+
+	const program = anchor.workspace.TeamDao as Program<TeamDao>;
+
+	let tournamentName = "Test Tournament 2";
+	let prizePool = anchor.web3.LAMPORTS_PER_SOL;
+
+	let tournamentPda;
+
+	before(async () => {
+		[tournamentPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("tournament"),
+				user.publicKey.toBuffer(),
+				Buffer.from(tournamentName),
+			],
+			program.programId
+		);
+
+		let slot = await program.provider.connection.getSlot();
+
+		await program.methods
+			.createTournament(
+				tournamentName,
+				new anchor.BN(1000),
+				new anchor.BN(prizePool),
+				new anchor.BN(slot),
+				new anchor.BN(slot + 1),
+				4,
+				user.publicKey,
+				5
+			)
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+	});
+
+	it("should create a tournament successfully", async () => {
+		const tournamentAccount = await program.account.tournamentAccount.fetch(
+			tournamentPda
+		);
+
+		assert.equal(tournamentAccount.name, tournamentName);
+		assert.equal(
+			tournamentAccount.organizer.toBase58(),
+			user.publicKey.toBase58()
+		);
+		assert.equal(tournamentAccount.prizePool.toNumber(), prizePool);
+		assert.deepEqual(tournamentAccount.status, { created: {} });
+	});
+
+	it("should not let anyone else other than organizer open registration", async () => {
+		let anotherUser = anchor.web3.Keypair.generate();
+		try {
+			await program.methods
+				.openRegistration()
+				.accounts({
+					tournamentAccount: tournamentPda,
+					organizer: anotherUser.publicKey,
+				})
+				.signers([anotherUser])
+				.rpc();
+			assert.fail("only the organizer can open registration");
+		} catch (err) {
+			assert.equal(
+				err.error.errorMessage,
+				"Only the organizer of the tournament can call this function"
+			);
+			assert.equal(err.error.errorCode.code, "NotOrganizerError");
+		}
+	});
+
+	it("should open and close registration successfully", async () => {
+		let events = [];
+		const openListener = program.addEventListener(
+			"RegistrationOpened",
+			(event) => events.push(["opened", event.tournament])
+		);
+		const closeListener = program.addEventListener(
+			"RegistrationClosed",
+			(event) => events.push(["closed", event.tournament])
+		);
+
+		await program.methods
+			.openRegistration()
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+
+		let { status } = await program.account.tournamentAccount.fetch(
+			tournamentPda
+		);
+		assert.deepEqual(status, { registrationOpen: {} });
+
+		await program.methods
+			.closeRegistration()
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+
+		({ status } = await program.account.tournamentAccount.fetch(
+			tournamentPda
+		));
+		assert.deepEqual(status, { registrationClosed: {} });
+
+		await new Promise((resolve) => setTimeout(resolve, 1000));
+		await program.removeEventListener(openListener);
+		await program.removeEventListener(closeListener);
+		assert.deepEqual(
+			events.map(([name, tournament]) => [name, tournament.toBase58()]),
+			[
+				["opened", tournamentPda.toBase58()],
+				["closed", tournamentPda.toBase58()],
+			]
+		);
+	});
+
+	it("should not close the tournament before it is finalized", async () => {
+		try {
+			await program.methods
+				.closeTournament()
+				.accounts({ tournamentAccount: tournamentPda })
+				.rpc();
+			assert.fail("the tournament should not close before it is finalized");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "InvalidTournamentStatusError");
+		}
+	});
+
+	it("should finalize the tournament successfully", async () => {
+		await program.methods
+			.finalizeTournament()
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+
+		let { status } = await program.account.tournamentAccount.fetch(
+			tournamentPda
+		);
+		assert.deepEqual(status, { finalized: {} });
+	});
+
+	it("should close the tournament and refund the organizer", async () => {
+		let balance = await program.provider.connection.getBalance(
+			user.publicKey
+		);
+		let tournamentBalance = await program.provider.connection.getBalance(
+			tournamentPda
+		);
+
+		await program.methods
+			.closeTournament()
+			.accounts({ tournamentAccount: tournamentPda })
+			.rpc();
+
+		let tournamentAccount =
+			await program.account.tournamentAccount.fetchNullable(tournamentPda);
+		assert.equal(tournamentAccount, null);
+
+		// the unused prize pool and the rent go back to the organizer, minus the fee
+		let balanceAfter = await program.provider.connection.getBalance(
+			user.publicKey
+		);
+		assert.isAbove(balanceAfter, balance + tournamentBalance - 10000);
+	});
+});
@@ -3,6 +3,7 @@ import { Program } from "@project-serum/anchor";
 import { decodeIdlAccount } from "@project-serum/anchor/dist/cjs/idl";
 import { MethodsBuilder } from "@project-serum/anchor/dist/cjs/program/namespace/methods";
 import { assert } from "chai";
+import { createHash } from "crypto";
 import { TeamDao } from "../target/types/team_dao";
 
 describe("Voting tests", () => {
@@ -22,7 +23,25 @@ describe("Voting tests", () => {
 	const program = anchor.workspace.TeamDao as Program<TeamDao>;
 
 	let teamName = "Test Team 2";
-	let uid = new anchor.BN(1234567);
+	// assigned by the registry in before
+	let uid;
+	// sha256 hash of the normalized name, reserves the name of the team
+	let nameHash = [
+		...createHash("sha256").update(teamName.trim().toLowerCase()).digest(),
+	];
+	// simple majority for the quorum and the approval of the team votes
+	let governance = { quorumPercentage: 51, approvalPercentage: 51 };
+	// 5v5 team format
+	let rosterSize = 5;
+	// optional metadata of the team
+	let metadata = {
+		tag: null,
+		logoUri: null,
+		bannerUri: null,
+		game: null,
+		region: null,
+		socialLinks: [],
+	};
 	let teamAccountAddr;
 
 	// the team addresses array
@@ -30,57 +49,146 @@ describe("Voting tests", () => {
 
 	let teamPda, teamBump;
 
+	let proposalPda;
+
+	// creates a proposal of the team with the next proposal id and returns its address
+	const createProposal = async (kind, payload: Buffer) => {
+		let { proposalCount } = await program.account.teamAccount.fetch(
+			teamAccountAddr
+		);
+		const [pda] = await anchor.web3.PublicKey.findProgramAddress(
+			[
+				Buffer.from("proposal"),
+				teamAccountAddr.toBuffer(),
+				proposalCount.toArrayLike(Buffer, "le", 8),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.createProposal(kind, payload, new anchor.BN(60 * 60))
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: pda })
+			.rpc();
+
+		return pda;
+	};
+
+	// the borsh serialized tournament address and prize mint, sol prizes use the default pubkey
+	const joinPayload = (tournamentAddress: anchor.web3.PublicKey) =>
+		Buffer.concat([
+			tournamentAddress.toBuffer(),
+			anchor.web3.PublicKey.default.toBuffer(),
+		]);
+
+	// casts the vote of each of the players for the proposal
+	const castVotes = async (pda, players, voteType) => {
+		for (let i = 0; i < players.length; i++) {
+			await program.methods
+				.castVote(voteType)
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: pda,
+					signer: players[i].publicKey,
+				})
+				.signers([players[i]])
+				.rpc();
+		}
+	};
+
 	before(async () => {
+		// creating the registry if this is the first test file that runs
+		const [registryPda] = await anchor.web3.PublicKey.findProgramAddress(
+			[Buffer.from("registry")],
+			program.programId
+		);
+		if (
+			(await program.provider.connection.getAccountInfo(registryPda)) == null
+		) {
+			await program.methods.initRegistry().rpc();
+		}
+		uid = (await program.account.registryAccount.fetch(registryPda)).teamCount;
+
 		// creating account here because i will use it in other tests
-		const ix = await program.methods.createTeam(teamName, uid);
+		const ix = await program.methods.createTeam(
+			teamName,
+			uid,
+			nameHash,
+			governance,
+			rosterSize,
+			rosterSize,
+			metadata
+		);
 		teamAccountAddr = (await ix.pubkeys()).teamAccount;
 		const tx = await ix.rpc();
 
 		[teamPda, teamBump] = await anchor.web3.PublicKey.findProgramAddress(
-			[Buffer.from(teamName), Buffer.from(`${uid}`)],
+			[Buffer.from(teamName), uid.toArrayLike(Buffer, "le", 8)],
 			program.programId
 		);
 
 		// adding team members
 		for (let i = 0; i < team.length; i++) {
-			await program.methods.addMember(teamName, uid, team[i].publicKey).rpc();
+			const [invitationPda] = await anchor.web3.PublicKey.findProgramAddress(
+				[
+					Buffer.from("invite"),
+					teamAccountAddr.toBuffer(),
+					team[i].publicKey.toBuffer(),
+				],
+				program.programId
+			);
+
+			// the captain invites the member and the member accepts the invitation
+			await program.methods
+				.inviteMember(team[i].publicKey)
+				.accounts({
+					teamAccount: teamAccountAddr,
+					invitationAccount: invitationPda,
+				})
+				.rpc();
+			await program.methods
+				.acceptInvite()
+				.accounts({
+					teamAccount: teamAccountAddr,
+					invitationAccount: invitationPda,
+					inviter: user.publicKey,
+					invitee: team[i].publicKey,
+				})
+				.signers([team[i]])
+				.rpc();
 		}
 
-		// initing tournament
-		await program.methods
-			.initTournament(teamName, uid, tournament.publicKey, new anchor.BN(100))
-			.rpc();
+		// proposing to join the tournament
+		proposalPda = await createProposal(
+			{ joinTournament: {} },
+			joinPayload(tournament.publicKey)
+		);
 	});
 
 	it("should vote yes successfully", async () => {
-		await program.methods.voteForTournament(teamName, uid, { yes: {} }).rpc();
+		await program.methods
+			.castVote({ yes: {} })
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: proposalPda })
+			.rpc();
 
-		const teamDetails = await program.account.teamAccount.fetch(
-			teamAccountAddr
-		);
+		const proposal = await program.account.proposalAccount.fetch(proposalPda);
 
-		assert.equal(
-			teamDetails.votedPlayers[0].toString(),
-			user.publicKey.toString()
-		);
-		assert.equal(teamDetails.yesVotes, 1);
+		assert.equal(proposal.voters[0].toString(), user.publicKey.toString());
+		assert.equal(proposal.yesVotes, 1);
 	});
 
 	it("should set tournament address successfully", async () => {
-		// remember it only sets the tournament address if the yes votes are more than 50%,
-		// in this case teams will only have 5 members so 3 yes votes will be enough
+		// teams will only have 5 members so 3 yes votes will be enough
 		// since we voted for captain already, we need to vote for 2 more members
-		for (let i = 0; i < 2; i++) {
-			await program.methods
-				.voteForTournament(teamName, uid, { yes: {} })
-				.accounts({
-					teamAccount: teamAccountAddr,
-					signer: team[i].publicKey,
-					systemProgram: anchor.web3.SystemProgram.programId,
-				})
-				.signers([team[i]])
-				.rpc();
-		}
+		await castVotes(proposalPda, team.slice(0, 2), { yes: {} });
+
+		const proposal = await program.account.proposalAccount.fetch(proposalPda);
+		assert.deepEqual(proposal.status, { passed: {} });
+
+		// the tournament is set when the passed proposal is executed
+		await program.methods
+			.executeProposal()
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: proposalPda })
+			.rpc();
 
 		const teamDetails = await program.account.teamAccount.fetch(
 			teamAccountAddr
@@ -90,29 +198,35 @@ describe("Voting tests", () => {
 			teamDetails.activeTournament.toBase58(),
 			tournament.publicKey.toBase58()
 		);
-		assert.equal(teamDetails.votingResult, true);
 	});
 
-	it("should not increase yes votes", async () => {
-		const teamDetails = await program.account.teamAccount.fetch(
-			teamAccountAddr
-		);
-
-		assert.equal(teamDetails.yesVotes, 0);
+	it("should not vote for a decided proposal", async () => {
+		try {
+			await castVotes(proposalPda, [carol], { yes: {} });
+			assert.fail("the proposal should be closed for votes");
+		} catch (err) {
+			assert.equal(err.error.errorCode.code, "ProposalNotActiveError");
+		}
 	});
 
 	it("should not init another tournament if there is still an active one", async () => {
 		let anotherTournament = anchor.web3.Keypair.generate();
+		const anotherProposalPda = await createProposal(
+			{ joinTournament: {} },
+			joinPayload(anotherTournament.publicKey)
+		);
+		await castVotes(anotherProposalPda, team.slice(0, 3), { yes: {} });
+
 		try {
-			// trying to vote for another tournament
+			// trying to join another tournament
 			await program.methods
-				.initTournament(
-					teamName,
-					uid,
-					anotherTournament.publicKey,
-					new anchor.BN(100)
-				)
+				.executeProposal()
+				.accounts({
+					teamAccount: teamAccountAddr,
+					proposalAccount: anotherProposalPda,
+				})
 				.rpc();
+			assert.fail("the team should not join another tournament");
 		} catch (err) {
 			assert.equal(
 				err.error.errorMessage,
@@ -124,18 +238,16 @@ describe("Voting tests", () => {
 
 	it("should let a team leave the tournament", async () => {
 		// leaving the tournament
-		// still, 3 votes for leaving the tournament is enough because of majority reasons and more than 3 votes will send an error
-		for (let i = 0; i < 3; i++) {
-			await program.methods
-				.leaveTournament(teamName, uid, { yes: {} })
-				.accounts({
-					teamAccount: teamAccountAddr,
-					signer: team[i].publicKey,
-					systemProgram: anchor.web3.SystemProgram.programId,
-				})
-				.signers([team[i]])
-				.rpc();
-		}
+		// still, 3 votes for leaving the tournament is enough because of majority reasons
+		const leavePda = await createProposal(
+			{ leaveTournament: {} },
+			Buffer.from([])
+		);
+		await castVotes(leavePda, team.slice(0, 3), { yes: {} });
+		await program.methods
+			.executeProposal()
+			.accounts({ teamAccount: teamAccountAddr, proposalAccount: leavePda })
+			.rpc();
 
 		let teamDetails = await program.account.teamAccount.fetch(teamAccountAddr);
 		assert.equal(
@@ -143,4 +255,54 @@ describe("Voting tests", () => {
 			"11111111111111111111111111111111" // Pubkey::default()
 		);
 	}); // testing for errors seemed unnecessary because almost the same errors are tested in the previous tests
+
+	it("should not count abstain votes as no", async () => {
+		let nextTournament = anchor.web3.Keypair.generate();
+		const nextProposalPda = await createProposal(
+			{ joinTournament: {} },
+			joinPayload(nextTournament.publicKey)
+		);
+
+		// 3 abstentions reach the quorum but do not reject the tournament
+		await castVotes(nextProposalPda, team.slice(0, 3), { abstain: {} });
+
+		let proposal = await program.account.proposalAccount.fetch(
+			nextProposalPda
+		);
+		assert.equal(proposal.abstainVotes, 3);
+		assert.deepEqual(proposal.status, { active: {} });
+
+		// the 2 yes votes are the only decisive votes, so they pass the proposal
+		await program.methods
+			.castVote({ yes: {} })
+			.accounts({
+				teamAccount: teamAccountAddr,
+				proposalAccount: nextProposalPda,
+			})
+			.rpc();
+		await castVotes(nextProposalPda, [dan], { yes: {} });
+
+		proposal = await program.account.proposalAccount.fetch(nextProposalPda);
+		assert.deepEqual(proposal.status, { passed: {} });
+	});
+
+	it("should pass the captain role to the first other member when the captain leaves", async () => {
+		// alice is the second member of the team
+		await program.methods
+			.transferCaptain(alice.publicKey)
+			.accounts({ teamAccount: teamAccountAddr })
+			.rpc();
+
+		await program.methods
+			.leaveTeam()
+			.accounts({ teamAccount: teamAccountAddr, signer: alice.publicKey })
+			.signers([alice])
+			.rpc();
+
+		let teamDetails = await program.account.teamAccount.fetch(teamAccountAddr);
+		assert.equal(teamDetails.captain.toBase58(), user.publicKey.toBase58());
+		assert.isFalse(
+			teamDetails.members.some((member) => member.equals(alice.publicKey))
+		);
+	});
 });